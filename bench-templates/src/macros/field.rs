@@ -18,6 +18,7 @@ macro_rules! f_bench {
                     sqrt,
                     // prime field stuff
                     bigint,
+                    batch_inversion,
                 );
             }
         }
@@ -48,11 +49,14 @@ macro_rules! f_bench {
                 use super::*;
                 use ark_ff::{Field, UniformRand};
                 field_common!($bench_group_name, $F);
+                cyclotomic!($bench_group_name, $F);
                 $crate::criterion_group!(
                     benches,
                     // common stuff
                     arithmetic,
                     serialization,
+                    // cyclotomic subgroup stuff
+                    cyclotomic,
                 );
             }
         }
@@ -130,6 +134,17 @@ macro_rules! field_common {
                     field_elements_left[i].inverse().unwrap()
                 })
             });
+            // The naive approach, `a^{-1} = a^(|F| - 2)`, as a baseline for `Inverse`.
+            let order_minus_two =
+                (<$F>::characteristic_biguint().pow(<$F>::extension_degree() as u32) - 2u8)
+                    .to_u64_digits();
+            arithmetic.bench_function("Inverse via exponentiation", |b| {
+                let mut i = 0;
+                b.iter(|| {
+                    i = (i + 1) % SAMPLES;
+                    field_elements_left[i].pow(&order_minus_two)
+                })
+            });
             arithmetic.bench_function("Sum of products of size 2", |b| {
                 let mut i = 0;
                 b.iter(|| {
@@ -272,6 +287,53 @@ macro_rules! sqrt {
     };
 }
 
+#[macro_export]
+macro_rules! cyclotomic {
+    ($bench_group_name:expr, $F:ident) => {
+        fn cyclotomic(c: &mut $crate::criterion::Criterion) {
+            use ark_ff::CyclotomicMultSubgroup;
+            const SAMPLES: usize = 1000;
+            // A typical number of consecutive squarings in a final exponentiation.
+            const SQUARINGS: usize = 63;
+            let name = format!("{}::{}", $bench_group_name, stringify!($F));
+
+            let mut rng = ark_std::test_rng();
+
+            // Map random elements into the cyclotomic subgroup via `f^(p^(d/2) - 1)`.
+            let half_degree = (<$F>::extension_degree() / 2) as usize;
+            let f = (0..SAMPLES)
+                .map(|_| {
+                    let f = <$F>::rand(&mut rng);
+                    let mut g = f;
+                    g.frobenius_map(half_degree);
+                    g * f.inverse().unwrap()
+                })
+                .collect::<Vec<_>>();
+            let mut cyclotomic = c.benchmark_group(format!("Cyclotomic for {name}"));
+
+            cyclotomic.bench_function(format!("{SQUARINGS} Cyclotomic Squarings"), |b| {
+                let mut i = 0;
+                b.iter(|| {
+                    i = (i + 1) % SAMPLES;
+                    let mut tmp = f[i];
+                    for _ in 0..SQUARINGS {
+                        tmp.cyclotomic_square_in_place();
+                    }
+                    tmp
+                })
+            });
+            cyclotomic.bench_function(format!("{SQUARINGS} Batched Cyclotomic Squarings"), |b| {
+                let mut i = 0;
+                b.iter(|| {
+                    i = (i + 1) % SAMPLES;
+                    f[i].cyclotomic_square_batch(SQUARINGS)
+                })
+            });
+            cyclotomic.finish();
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! prime_field {
     ($bench_group_name:expr, $F:ident) => {
@@ -404,5 +466,39 @@ macro_rules! prime_field {
             });
             conversions.finish()
         }
+
+        fn batch_inversion(c: &mut $crate::criterion::Criterion) {
+            use ark_ff::One;
+            use $crate::BenchmarkId;
+            let name = format!("{}::{}", $bench_group_name, stringify!($F));
+            let mut rng = ark_std::test_rng();
+            let mut batch_inversion = c.benchmark_group(format!("Batch inversion for {name}"));
+            // With the `parallel` feature, this shows the slice lengths at which
+            // splitting the work across threads starts to pay off for each
+            // minimum chunk size.
+            for size in [1 << 6, 1 << 10, 1 << 14] {
+                let v = (0..size).map(|_| <$F>::rand(&mut rng)).collect::<Vec<_>>();
+                for min_elements_per_thread in [1, 64, 1024, usize::MAX] {
+                    let label = if min_elements_per_thread == usize::MAX {
+                        "Serial".to_string()
+                    } else {
+                        format!("Minimum of {min_elements_per_thread} elements per thread")
+                    };
+                    let id = BenchmarkId::new(label, size);
+                    batch_inversion.bench_with_input(id, &v, |b, v| {
+                        b.iter(|| {
+                            let mut v = v.clone();
+                            ark_ff::batch_inversion_and_mul_with_threshold(
+                                &mut v,
+                                &<$F>::one(),
+                                min_elements_per_thread,
+                            );
+                            v
+                        })
+                    });
+                }
+            }
+            batch_inversion.finish();
+        }
     };
 }
@@ -36,6 +36,10 @@ pub fn to_sign_and_limbs(input: TokenStream) -> TokenStream {
 /// * `small_subgroup_base` and `small_subgroup_power` (optional): If the field
 ///   has insufficient two-adicity, specify an additional subgroup of size
 ///   `small_subgroup_base.pow(small_subgroup_power)`.
+///
+/// The macro only implements `MontConfig`; the backend is picked where the
+/// field type is named, e.g. `Fp256<MontBackend<Config, 4>>`. Using
+/// `MontBackend128` instead is always a manual choice at that point.
 // This code was adapted from the `PrimeField` Derive Macro in ff-derive.
 #[proc_macro_derive(
     MontConfig,
@@ -25,8 +25,25 @@ pub fn str_to_limbs(num: &str) -> (bool, Vec<String>) {
     (sign, limbs.into_iter().map(|l| format!("{l}u64")).collect())
 }
 
+/// Parses a decimal integer, or a hexadecimal one if prefixed with `0x`.
+/// Either may be preceded by a minus sign.
+fn parse_bigint(num: &str) -> Option<BigInt> {
+    let (is_negative, magnitude) = match num.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, num),
+    };
+    let magnitude = match magnitude
+        .strip_prefix("0x")
+        .or_else(|| magnitude.strip_prefix("0X"))
+    {
+        Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16)?,
+        None => BigInt::from_str(magnitude).ok()?,
+    };
+    Some(if is_negative { -magnitude } else { magnitude })
+}
+
 pub fn str_to_limbs_u64(num: &str) -> (bool, Vec<u64>) {
-    let (sign, digits) = BigInt::from_str(num)
+    let (sign, digits) = parse_bigint(num)
         .expect("could not parse to bigint")
         .to_radix_le(16);
     let limbs = digits
@@ -1,3 +1,4 @@
+use crate::{BigInt, BigInteger};
 use ark_std::{vec, vec::Vec};
 
 macro_rules! adc {
@@ -131,6 +132,82 @@ pub fn mac_with_carry(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
     tmp as u64
 }
 
+/// Computes `a + b`, returning the sum and whether the addition overflowed.
+/// Limbs are in little-endian order.
+#[inline]
+pub fn add_limbs_with_carry<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = BigInt(*a);
+    let carry = result.add_with_carry(&BigInt(*b));
+    (result.0, carry)
+}
+
+/// Computes `a - b`, returning the difference and whether the subtraction
+/// underflowed. Limbs are in little-endian order.
+#[inline]
+pub fn sub_limbs_with_borrow<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = BigInt(*a);
+    let borrow = result.sub_with_borrow(&BigInt(*b));
+    (result.0, borrow)
+}
+
+/// Computes the full `2N`-limb product `a * b`, returned as `(lo, hi)`.
+/// Limbs are in little-endian order.
+#[inline]
+pub fn mul_limbs_wide<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+    let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
+    for i in 0..N {
+        let mut carry = 0;
+        for (j, b_j) in b.iter().enumerate() {
+            let k = i + j;
+            if k >= N {
+                hi[k - N] = mac_with_carry(hi[k - N], a[i], *b_j, &mut carry);
+            } else {
+                lo[k] = mac_with_carry(lo[k], a[i], *b_j, &mut carry);
+            }
+        }
+        hi[i] = carry;
+    }
+    (lo, hi)
+}
+
+/// Computes `(hi * 2^(64N) + lo) * 2^(-64N) mod modulus`, where `inv` is
+/// `-modulus^{-1} mod 2^64`. This is Montgomery reduction with
+/// `R = 2^(64N)`.
+///
+/// The result is fully reduced provided that the input is less than
+/// `modulus * 2^(64N)`, as is the case for the output of [`mul_limbs_wide`]
+/// on two reduced inputs.
+#[inline]
+pub fn montgomery_reduce<const N: usize>(
+    lo: &[u64; N],
+    hi: &[u64; N],
+    modulus: &[u64; N],
+    inv: u64,
+) -> [u64; N] {
+    let (mut lo, mut hi) = (*lo, *hi);
+    let mut carry2 = 0;
+    for i in 0..N {
+        let k = lo[i].wrapping_mul(inv);
+        let mut carry = 0;
+        mac_with_carry(lo[i], k, modulus[0], &mut carry);
+        for (j, modulus_j) in modulus.iter().enumerate().skip(1) {
+            let idx = i + j;
+            if idx >= N {
+                hi[idx - N] = mac_with_carry(hi[idx - N], k, *modulus_j, &mut carry);
+            } else {
+                lo[idx] = mac_with_carry(lo[idx], k, *modulus_j, &mut carry);
+            }
+        }
+        carry2 = adc(&mut hi[i], carry, carry2);
+    }
+    let (reduced, borrow) = sub_limbs_with_borrow(&hi, modulus);
+    if carry2 != 0 || !borrow {
+        reduced
+    } else {
+        hi
+    }
+}
+
 /// Compute the NAF (non-adjacent form) of num
 pub fn find_naf(num: &[u64]) -> Vec<i8> {
     let is_zero = |num: &[u64]| num.iter().all(|x| *x == 0u64);
@@ -258,3 +335,58 @@ fn test_find_relaxed_naf_correctness() {
         assert_eq!(test, test_expected);
     }
 }
+
+#[test]
+fn test_limb_arithmetic_matches_fp() {
+    use ark_std::UniformRand;
+    use ark_test_curves::{bls12_381::Fr, Field, PrimeField};
+
+    let mut rng = ark_std::test_rng();
+    let modulus = Fr::MODULUS.0;
+
+    for _ in 0..100 {
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        let (a_limbs, b_limbs) = (a.into_bigint().0, b.into_bigint().0);
+
+        // Addition followed by a conditional subtraction of the modulus.
+        let (sum, carry) = add_limbs_with_carry(&a_limbs, &b_limbs);
+        let (reduced, borrow) = sub_limbs_with_borrow(&sum, &modulus);
+        let sum = if carry || !borrow { reduced } else { sum };
+        assert_eq!(sum, (a + b).into_bigint().0);
+
+        // Subtraction followed by a conditional addition of the modulus.
+        let (diff, borrow) = sub_limbs_with_borrow(&a_limbs, &b_limbs);
+        let diff = if borrow {
+            add_limbs_with_carry(&diff, &modulus).0
+        } else {
+            diff
+        };
+        assert_eq!(diff, (a - b).into_bigint().0);
+
+        // Montgomery multiplication on the internal representation.
+        let (lo, hi) = mul_limbs_wide(&(a.0).0, &(b.0).0);
+        assert_eq!(
+            montgomery_reduce(&lo, &hi, &modulus, Fr::INV),
+            ((a * b).0).0
+        );
+
+        // Reducing the plain product yields `a * b / R`.
+        let (lo, hi) = mul_limbs_wide(&a_limbs, &b_limbs);
+        let expected = a * b * Fr::from(Fr::R).inverse().unwrap();
+        assert_eq!(
+            montgomery_reduce(&lo, &hi, &modulus, Fr::INV),
+            expected.into_bigint().0
+        );
+
+        let product =
+            num_bigint::BigUint::from(a.into_bigint()) * num_bigint::BigUint::from(b.into_bigint());
+        let mut limbs = lo.to_vec();
+        limbs.extend_from_slice(&hi);
+        let bytes = limbs
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(num_bigint::BigUint::from_bytes_le(&bytes), product);
+    }
+}
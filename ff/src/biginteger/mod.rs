@@ -16,14 +16,20 @@ use ark_std::{
         distributions::{Distribution, Standard},
         Rng,
     },
+    string::String,
     vec::Vec,
 };
 use num_bigint::BigUint;
+use subtle::{Choice, ConditionallySelectable};
 use zeroize::Zeroize;
 
 #[macro_use]
 pub mod arithmetic;
 
+mod signed;
+pub use signed::Signed;
+use signed::WideSigned;
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Zeroize)]
 pub struct BigInt<const N: usize>(pub [u64; N]);
 
@@ -168,7 +174,7 @@ impl<const N: usize> BigInt<N> {
         result
     }
 
-    const fn const_geq(&self, other: &Self) -> bool {
+    pub(crate) const fn const_geq(&self, other: &Self) -> bool {
         const_for!((i in 0..N) {
             let a = self.0[N - i - 1];
             let b = other.0[N - i - 1];
@@ -284,6 +290,29 @@ impl<const N: usize> BigInt<N> {
             crate::const_helpers::R2Buffer::<N>([0u64; N], [0u64; N], 1);
         const_modulo!(two_pow_n_times_64_square, self)
     }
+
+    /// Computes the Barrett constant `floor(2^(2k) / self)`, where `k` is the
+    /// bit length of `self`. The result fits in `N` limbs provided that
+    /// `k < 64 * N`.
+    #[doc(hidden)]
+    pub const fn barrett_mu(&self) -> Self {
+        // Base-2 long division, as in `const_modulo!`. The only set bit of the
+        // dividend is bit `2k`, so it enters the remainder on the first step.
+        assert!(!self.const_is_zero());
+        let k = self.const_num_bits() as usize;
+        let mut quotient = Self::zero();
+        let mut remainder = Self::one();
+        let mut i = 2 * k as isize;
+        while i >= 0 {
+            if remainder.const_geq(self) {
+                remainder = remainder.const_sub_with_borrow(self).0;
+                quotient.0[i as usize / 64] |= 1 << (i as usize % 64);
+            }
+            remainder = remainder.const_mul2();
+            i -= 1;
+        }
+        quotient
+    }
 }
 
 impl<const N: usize> BigInteger for BigInt<N> {
@@ -483,13 +512,21 @@ impl<const N: usize> BigInteger for BigInt<N> {
 
     #[inline]
     fn get_bit(&self, i: usize) -> bool {
-        if i >= 64 * N {
-            false
-        } else {
-            let limb = i / 64;
-            let bit = i - (64 * limb);
-            (self.0[limb] & (1 << bit)) != 0
-        }
+        assert!(i < 64 * N, "bit index out of range");
+        let (limb, bit) = (i / 64, i % 64);
+        (self.0[limb] & (1 << bit)) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, i: usize, value: bool) {
+        assert!(i < 64 * N, "bit index out of range");
+        let (limb, bit) = (i / 64, i % 64);
+        self.0[limb] = (self.0[limb] & !(1 << bit)) | (u64::from(value) << bit);
+    }
+
+    #[inline]
+    fn count_ones(&self) -> u32 {
+        self.0.iter().map(|limb| limb.count_ones()).sum()
     }
 
     #[inline]
@@ -538,6 +575,16 @@ impl<const N: usize> BigInteger for BigInt<N> {
     }
 }
 
+impl<const N: usize> ConditionallySelectable for BigInt<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = *a;
+        for (r, b) in result.0.iter_mut().zip(b.0) {
+            r.conditional_assign(&b, choice);
+        }
+        result
+    }
+}
+
 impl<const N: usize> UpperHex for BigInt<N> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:016X}", BigUint::from(*self))
@@ -924,6 +971,11 @@ pub trait BigInteger:
     fn num_bits(&self) -> u32;
 
     /// Compute the `i`-th bit of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not smaller than the width of `self` in bits.
+    ///
     /// # Example
     ///
     /// ```
@@ -935,6 +987,36 @@ pub trait BigInteger:
     /// ```
     fn get_bit(&self, i: usize) -> bool;
 
+    /// Sets the `i`-th bit of `self` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not smaller than the width of `self` in bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger128 as B, BigInteger as _};
+    ///
+    /// let mut a = B::from(1u64);
+    /// a.set_bit(64, true);
+    /// a.set_bit(0, false);
+    /// assert_eq!(a, B::new([0, 1]));
+    /// ```
+    fn set_bit(&mut self, i: usize, value: bool);
+
+    /// Returns the number of ones in the binary representation of `self`,
+    /// i.e. its Hamming weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger128 as B, BigInteger as _};
+    ///
+    /// assert_eq!(B::new([0b1011, u64::MAX]).count_ones(), 67);
+    /// ```
+    fn count_ones(&self) -> u32;
+
     /// Returns the big integer representation of a given big endian boolean
     /// array.
     /// # Example
@@ -1027,6 +1109,342 @@ pub trait BigInteger:
     /// ```
     fn to_bytes_le(&self) -> Vec<u8>;
 
+    /// Parses a string of digits in base `radix` into a [`BigInteger`],
+    /// without going through [`BigUint`]. Both lower- and upper-case letters
+    /// are accepted as digits. Returns `None` if `s` is empty, contains an
+    /// invalid digit, or represents a value that does not fit into `Self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=16`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger128 as B, BigInteger as _};
+    ///
+    /// assert_eq!(B::from_str_radix("12345", 10), Some(B::from(12345u64)));
+    /// assert_eq!(B::from_str_radix("fF", 16), Some(B::from(255u64)));
+    /// assert_eq!(B::from_str_radix("12", 2), None);
+    /// // 2^128 does not fit into 128 bits.
+    /// assert_eq!(B::from_str_radix(&format!("1{}", "0".repeat(32)), 16), None);
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Option<Self> {
+        assert!(
+            (2..=16).contains(&radix),
+            "radix must be in the range 2..=16"
+        );
+        if s.is_empty() {
+            return None;
+        }
+        let mut result = Self::from(0u64);
+        for c in s.chars() {
+            // Compute `result * radix + digit`.
+            let mut carry = u64::from(c.to_digit(radix)?);
+            for limb in result.as_mut() {
+                *limb = arithmetic::mac_with_carry(0, *limb, u64::from(radix), &mut carry);
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(result)
+    }
+
+    /// Returns the representation of `self` in base `radix`, using lower-case
+    /// letters for digits above 9. This inverts [`BigInteger::from_str_radix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=16`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger128 as B, BigInteger as _};
+    ///
+    /// assert_eq!(B::from(255u64).to_str_radix(16), "ff");
+    /// assert_eq!(B::from(5u64).to_str_radix(2), "101");
+    /// assert_eq!(B::from(0u64).to_str_radix(10), "0");
+    /// ```
+    fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (2..=16).contains(&radix),
+            "radix must be in the range 2..=16"
+        );
+        if self.is_zero() {
+            return String::from("0");
+        }
+        let mut digits = Vec::new();
+        let mut value = *self;
+        while !value.is_zero() {
+            // Divide `value` by `radix`, from the most significant limb down.
+            let mut rem = 0u64;
+            for limb in value.as_mut().iter_mut().rev() {
+                let tmp = (u128::from(rem) << 64) | u128::from(*limb);
+                *limb = (tmp / u128::from(radix)) as u64;
+                rem = (tmp % u128::from(radix)) as u64;
+            }
+            digits.push(char::from_digit(rem as u32, radix).unwrap());
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, computed with
+    /// the binary (Stein's) algorithm. The GCD of zero and zero is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger64 as B, BigInteger as _};
+    ///
+    /// assert_eq!(B::from(12u64).gcd(&B::from(18u64)), B::from(6u64));
+    /// assert_eq!(B::from(0u64).gcd(&B::from(5u64)), B::from(5u64));
+    /// ```
+    fn gcd(&self, other: &Self) -> Self {
+        if self.is_zero() {
+            return *other;
+        }
+        if other.is_zero() {
+            return *self;
+        }
+
+        let (mut u, mut v) = (*self, *other);
+        let mut shift = 0;
+        while u.is_even() && v.is_even() {
+            u.div2();
+            v.div2();
+            shift += 1;
+        }
+        while u.is_even() {
+            u.div2();
+        }
+        // `u` is now odd, and stays odd.
+        loop {
+            while v.is_even() {
+                v.div2();
+            }
+            if u > v {
+                core::mem::swap(&mut u, &mut v);
+            }
+            v.sub_with_borrow(&u);
+            if v.is_zero() {
+                break;
+            }
+        }
+        u.muln(shift);
+        u
+    }
+
+    /// Returns `(g, x, y)` such that `g` is the greatest common divisor of
+    /// `self` and `other`, and `g = self * x + other * y`.
+    ///
+    /// The Bézout coefficients are computed with the binary extended GCD
+    /// algorithm, and may be negative, so they are returned as [`Signed`]
+    /// integers. Their magnitudes are bounded by `max(self, other)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::{BigInteger64 as B, Signed}, BigInteger as _};
+    ///
+    /// let (g, x, y) = B::from(240u64).extended_gcd(&B::from(46u64));
+    /// assert_eq!(g, B::from(2u64));
+    /// // 240 * 14 - 46 * 73 = 2
+    /// assert_eq!(x, Signed::new(B::from(14u64), false));
+    /// assert_eq!(y, Signed::new(B::from(73u64), true));
+    /// ```
+    fn extended_gcd(&self, other: &Self) -> (Self, Signed<Self>, Signed<Self>) {
+        let zero = Signed::from(Self::from(0u64));
+        let one = Signed::from(Self::from(1u64));
+        if other.is_zero() {
+            return (*self, one, zero);
+        }
+        if self.is_zero() {
+            return (*other, zero, one);
+        }
+
+        let (mut x, mut y) = (*self, *other);
+        let mut shift = 0;
+        while x.is_even() && y.is_even() {
+            x.div2();
+            y.div2();
+            shift += 1;
+        }
+        let (wide_x, wide_y) = (WideSigned::new(x, false), WideSigned::new(y, false));
+
+        // Maintain `x * a + y * b = u` and `x * c + y * d = v`, with
+        // `0 <= a, c <= y` and `|b|, |d| <= x`.
+        let (mut u, mut v) = (x, y);
+        let (mut a, mut b) = (
+            WideSigned::new(Self::from(1u64), false),
+            WideSigned::new(Self::from(0u64), false),
+        );
+        let (mut c, mut d) = (
+            WideSigned::new(Self::from(0u64), false),
+            WideSigned::new(Self::from(1u64), false),
+        );
+        // Halves `u` alongside its coefficients `a` and `b`. If they are not
+        // both even, `(a + y, b - x)` are, since `x` and `y` are not both even.
+        let halve = |a: &mut WideSigned<Self>, b: &mut WideSigned<Self>| {
+            if !(a.is_even() && b.is_even()) {
+                *a = a.add(&wide_y);
+                *b = b.sub(&wide_x);
+            }
+            a.halve();
+            b.halve();
+        };
+        // Subtracts the coefficients `(c, d)` from `(a, b)`, and adds `(y, -x)`
+        // to the result if needed to keep `a` non-negative.
+        let reduce = |a: &mut WideSigned<Self>,
+                      b: &mut WideSigned<Self>,
+                      c: &WideSigned<Self>,
+                      d: &WideSigned<Self>| {
+            *a = a.sub(c);
+            *b = b.sub(d);
+            if a.is_negative() {
+                *a = a.add(&wide_y);
+                *b = b.sub(&wide_x);
+            }
+        };
+        loop {
+            while u.is_even() {
+                u.div2();
+                halve(&mut a, &mut b);
+            }
+            while v.is_even() {
+                v.div2();
+                halve(&mut c, &mut d);
+            }
+            if u >= v {
+                u.sub_with_borrow(&v);
+                reduce(&mut a, &mut b, &c, &d);
+            } else {
+                v.sub_with_borrow(&u);
+                reduce(&mut c, &mut d, &a, &b);
+            }
+            if u.is_zero() {
+                break;
+            }
+        }
+        v.muln(shift);
+        (v, c.into_signed(), d.into_signed())
+    }
+
+    /// Returns the quotient and remainder of dividing `self` by `divisor`,
+    /// computed with Knuth's Algorithm D (TAOCP Vol. 2, Section 4.3.1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::{biginteger::BigInteger128 as B, BigInteger as _};
+    ///
+    /// let (q, r) = B::from(100u64).div_rem(&B::from(7u64));
+    /// assert_eq!(q, B::from(14u64));
+    /// assert_eq!(r, B::from(2u64));
+    /// ```
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero");
+        let mut quotient = Self::from(0u64);
+        if self < divisor {
+            return (quotient, *self);
+        }
+
+        // The number of significant limbs of the divisor and the dividend.
+        let significant_limbs = |x: &Self| {
+            x.as_ref()
+                .iter()
+                .rposition(|&limb| limb != 0)
+                .map_or(0, |i| i + 1)
+        };
+        let n = significant_limbs(divisor);
+        let m = significant_limbs(self) - n;
+        let (u, v) = (self.as_ref(), divisor.as_ref());
+
+        if n == 1 {
+            // Short division by a single limb.
+            let mut rem = 0u64;
+            for (q, &limb) in quotient.as_mut().iter_mut().zip(u).rev() {
+                let tmp = (u128::from(rem) << 64) | u128::from(limb);
+                *q = (tmp / u128::from(v[0])) as u64;
+                rem = (tmp % u128::from(v[0])) as u64;
+            }
+            return (quotient, Self::from(rem));
+        }
+
+        // Normalize so that the most significant limb of the divisor has its
+        // top bit set, which guarantees that each quotient estimate is off by
+        // at most two.
+        let shift = v[n - 1].leading_zeros();
+        let shl = |x: &[u64], len: usize| -> Vec<u64> {
+            let mut result = vec![0u64; len];
+            for (i, r) in result.iter_mut().enumerate() {
+                let lo = x.get(i).copied().unwrap_or(0) << shift;
+                let hi = match (i, shift) {
+                    (0, _) | (_, 0) => 0,
+                    _ => x.get(i - 1).copied().unwrap_or(0) >> (64 - shift),
+                };
+                *r = lo | hi;
+            }
+            result
+        };
+        let vn = shl(&v[..n], n);
+        let mut un = shl(&u[..m + n], m + n + 1);
+
+        let base = 1u128 << 64;
+        for j in (0..=m).rev() {
+            // Estimate the quotient limb from the top two limbs of the
+            // remainder and the top limb of the divisor, and refine it using
+            // the second limb of the divisor.
+            let top = (u128::from(un[j + n]) << 64) | u128::from(un[j + n - 1]);
+            let mut qhat = top / u128::from(vn[n - 1]);
+            let mut rhat = top % u128::from(vn[n - 1]);
+            while qhat >= base
+                || qhat * u128::from(vn[n - 2]) > ((rhat << 64) | u128::from(un[j + n - 2]))
+            {
+                qhat -= 1;
+                rhat += u128::from(vn[n - 1]);
+                if rhat >= base {
+                    break;
+                }
+            }
+
+            // Subtract `qhat * vn` from the current window of the remainder.
+            let mut borrow = 0u64;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let product = arithmetic::mac_with_carry(0, qhat as u64, vn[i], &mut carry);
+                borrow = arithmetic::sbb(&mut un[i + j], product, borrow);
+            }
+            borrow = arithmetic::sbb(&mut un[j + n], carry, borrow);
+
+            // The estimate was one too large, so add the divisor back.
+            if borrow != 0 {
+                qhat -= 1;
+                let mut carry = 0u64;
+                for i in 0..n {
+                    carry = arithmetic::adc(&mut un[i + j], vn[i], carry);
+                }
+                arithmetic::adc(&mut un[j + n], 0, carry);
+            }
+            quotient.as_mut()[j] = qhat as u64;
+        }
+
+        // Undo the normalization to obtain the remainder.
+        let mut remainder = Self::from(0u64);
+        for (i, r) in remainder.as_mut().iter_mut().take(n).enumerate() {
+            *r = un[i] >> shift;
+            if shift > 0 {
+                *r |= un[i + 1] << (64 - shift);
+            }
+        }
+        (quotient, remainder)
+    }
+
     /// Returns the windowed non-adjacent form of `self`, for a window of size `w`.
     fn find_wnaf(&self, w: usize) -> Option<Vec<i64>> {
         // w > 2 due to definition of wNAF, and w < 64 to make sure that `i64`
@@ -0,0 +1,132 @@
+use super::BigInteger;
+use ark_std::cmp::Ordering;
+use num_bigint::{BigInt as NumBigInt, Sign};
+
+/// A signed integer, represented by the magnitude `abs` and its sign.
+///
+/// [`BigInteger`] is unsigned, so this is used wherever a signed result is
+/// needed, such as for the Bézout coefficients returned by
+/// [`BigInteger::extended_gcd`]. Zero is never negative.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Signed<B: BigInteger> {
+    pub abs: B,
+    pub is_negative: bool,
+}
+
+impl<B: BigInteger> Signed<B> {
+    /// Constructs the signed integer `abs` if `!is_negative`, and `-abs` otherwise.
+    pub fn new(abs: B, is_negative: bool) -> Self {
+        Self {
+            abs,
+            is_negative: is_negative && !abs.is_zero(),
+        }
+    }
+}
+
+impl<B: BigInteger> From<B> for Signed<B> {
+    fn from(abs: B) -> Self {
+        Self::new(abs, false)
+    }
+}
+
+impl<B: BigInteger> From<Signed<B>> for NumBigInt {
+    fn from(val: Signed<B>) -> NumBigInt {
+        let sign = if val.is_negative {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        NumBigInt::from_biguint(sign, val.abs.into())
+    }
+}
+
+/// A signed integer with one more bit of magnitude than `B`, so that sums of
+/// two values of `B` never overflow.
+#[derive(Copy, Clone)]
+pub(super) struct WideSigned<B: BigInteger> {
+    abs: B,
+    overflow: bool,
+    is_negative: bool,
+}
+
+impl<B: BigInteger> WideSigned<B> {
+    pub(super) fn new(abs: B, is_negative: bool) -> Self {
+        Self {
+            abs,
+            overflow: false,
+            is_negative: is_negative && !abs.is_zero(),
+        }
+    }
+
+    pub(super) fn is_even(&self) -> bool {
+        self.abs.is_even()
+    }
+
+    pub(super) fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.overflow && self.abs.is_zero()
+    }
+
+    fn cmp_abs(&self, other: &Self) -> Ordering {
+        self.overflow
+            .cmp(&other.overflow)
+            .then_with(|| self.abs.cmp(&other.abs))
+    }
+
+    /// Computes `self + other`.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        if self.is_negative == other.is_negative {
+            let mut abs = self.abs;
+            let carry = abs.add_with_carry(&other.abs);
+            let overflow = self.overflow as u8 + other.overflow as u8 + carry as u8;
+            assert!(overflow <= 1, "intermediate value out of range");
+            Self {
+                abs,
+                overflow: overflow == 1,
+                is_negative: self.is_negative,
+            }
+        } else {
+            let (larger, smaller) = match self.cmp_abs(other) {
+                Ordering::Less => (other, self),
+                _ => (self, other),
+            };
+            let mut abs = larger.abs;
+            let borrow = abs.sub_with_borrow(&smaller.abs);
+            let overflow = larger.overflow as u8 - smaller.overflow as u8 - borrow as u8;
+            let mut result = Self {
+                abs,
+                overflow: overflow == 1,
+                is_negative: larger.is_negative,
+            };
+            result.is_negative &= !result.is_zero();
+            result
+        }
+    }
+
+    /// Computes `self - other`.
+    pub(super) fn sub(&self, other: &Self) -> Self {
+        let mut neg_other = *other;
+        neg_other.is_negative = !other.is_negative && !other.is_zero();
+        self.add(&neg_other)
+    }
+
+    /// Divides `self` by two, which must be even.
+    pub(super) fn halve(&mut self) {
+        debug_assert!(self.is_even());
+        self.abs.div2();
+        if self.overflow {
+            self.abs.as_mut()[B::NUM_LIMBS - 1] |= 1 << 63;
+            self.overflow = false;
+        }
+    }
+
+    /// Converts `self` into a [`Signed`], panicking if its magnitude does not
+    /// fit into `B`.
+    pub(super) fn into_signed(self) -> Signed<B> {
+        assert!(!self.overflow, "value out of range");
+        Signed::new(self.abs, self.is_negative)
+    }
+}
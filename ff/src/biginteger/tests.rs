@@ -1,5 +1,7 @@
 use crate::{biginteger::BigInteger, UniformRand};
-use num_bigint::BigUint;
+use ark_std::{rand::Rng, string::ToString};
+use num_bigint::{BigInt as NumBigInt, BigUint};
+use num_integer::Integer;
 
 // Test elementary math operations for BigInteger.
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
@@ -72,6 +74,45 @@ fn biginteger_bits_test<B: BigInteger>() {
     assert!(!thirty_two.get_bit(4));
     // 5th bit of BigInteger representing 32 is 1
     assert!(thirty_two.get_bit(5), "{:?}", thirty_two);
+
+    // Set and clear bits on both sides of each limb boundary.
+    let num_bits = 64 * B::NUM_LIMBS;
+    let mut a = B::from(0u64);
+    for i in (64..num_bits).step_by(64) {
+        a.set_bit(i - 1, true);
+        a.set_bit(i, true);
+    }
+    for (j, limb) in a.as_ref().iter().enumerate() {
+        let mut expected = 0;
+        if j > 0 {
+            expected |= 1;
+        }
+        if j + 1 < B::NUM_LIMBS {
+            expected |= 1 << 63;
+        }
+        assert_eq!(*limb, expected);
+    }
+    assert_eq!(a.count_ones() as usize, 2 * (B::NUM_LIMBS - 1));
+    for i in 0..num_bits {
+        let expected = (i % 64 == 63 && i + 1 < num_bits) || (i % 64 == 0 && i > 0);
+        assert_eq!(a.get_bit(i), expected);
+    }
+    for i in (64..num_bits).step_by(64) {
+        a.set_bit(i - 1, false);
+        a.set_bit(i, true);
+    }
+    assert_eq!(a.count_ones() as usize, B::NUM_LIMBS - 1);
+
+    // `set_bit` and `count_ones` agree with `get_bit` on random values.
+    let mut rng = ark_std::test_rng();
+    let a = B::rand(&mut rng);
+    let mut b = B::from(0u64);
+    for i in 0..num_bits {
+        b.set_bit(i, a.get_bit(i));
+    }
+    assert_eq!(a, b);
+    let expected = (0..num_bits).filter(|&i| a.get_bit(i)).count();
+    assert_eq!(a.count_ones() as usize, expected);
 }
 
 // Test conversion from BigInteger to BigUint
@@ -85,6 +126,129 @@ fn biginteger_conversion_test<B: BigInteger>() {
     assert_eq!(x, x_recovered);
 }
 
+// Test gcd and extended_gcd against BigUint::gcd
+fn biginteger_gcd_test<B: BigInteger>() {
+    let mut rng = ark_std::test_rng();
+    let to_biguint = |x: B| -> BigUint { x.into() };
+    // Random values with a random number of bits.
+    let mut random = || {
+        let mut x = B::rand(&mut rng);
+        x.divn(rng.gen_range(0..(64 * B::NUM_LIMBS as u32)));
+        x
+    };
+
+    let mut cases = vec![
+        (B::from(0u64), B::from(0u64)),
+        (B::from(0u64), B::from(7u64)),
+        (B::from(12u64), B::from(0u64)),
+        (B::from(1u64), B::from(1u64)),
+    ];
+    for i in 0..100 {
+        let (a, b) = (random(), random());
+        cases.push((a, b));
+        // Also include values with a large common factor.
+        if i % 2 == 0 {
+            let factor = to_biguint(random());
+            let a = B::try_from(to_biguint(a) * &factor);
+            let b = B::try_from(to_biguint(b) * &factor);
+            if let (Ok(a), Ok(b)) = (a, b) {
+                cases.push((a, b));
+            }
+        }
+    }
+    let mut max = B::from(0u64);
+    max.sub_with_borrow(&B::from(1u64));
+    let mut max_minus_one = max;
+    max_minus_one.sub_with_borrow(&B::from(1u64));
+    cases.push((max, max_minus_one));
+    cases.push((max_minus_one, max));
+    cases.push((max, max));
+
+    for (a, b) in cases {
+        let expected = to_biguint(a).gcd(&to_biguint(b));
+        assert_eq!(to_biguint(a.gcd(&b)), expected);
+
+        let (g, x, y) = a.extended_gcd(&b);
+        assert_eq!(to_biguint(g), expected);
+        let a_x = NumBigInt::from(to_biguint(a)) * NumBigInt::from(x);
+        let b_y = NumBigInt::from(to_biguint(b)) * NumBigInt::from(y);
+        assert_eq!(a_x + b_y, NumBigInt::from(expected));
+    }
+}
+
+// Test div_rem against BigUint division
+fn biginteger_div_rem_test<B: BigInteger>() {
+    let mut rng = ark_std::test_rng();
+    let to_biguint = |x: B| -> BigUint { x.into() };
+    // Random values with a random number of bits.
+    let mut random = || {
+        let mut x = B::rand(&mut rng);
+        x.divn(rng.gen_range(0..(64 * B::NUM_LIMBS as u32)));
+        x
+    };
+
+    let mut max = B::from(0u64);
+    max.sub_with_borrow(&B::from(1u64));
+    let mut cases = vec![
+        (B::from(0u64), B::from(1u64)),
+        (B::from(7u64), B::from(7u64)),
+        (max, B::from(1u64)),
+        (max, max),
+        (B::from(1u64), max),
+    ];
+    if B::NUM_LIMBS >= 4 {
+        // A case where the initial quotient estimate is too large, and the
+        // divisor has to be added back.
+        let (mut a, mut b) = (B::from(0u64), B::from(0u64));
+        a.as_mut()[..4].copy_from_slice(&[0, 2, 0x80000000001eead6, 0x7bab5]);
+        b.as_mut()[..3].copy_from_slice(&[u64::MAX, 0, 0x8000000000000002]);
+        cases.push((a, b));
+    }
+    for _ in 0..1000 {
+        let (a, b) = (random(), random());
+        if !b.is_zero() {
+            // Covers both `divisor <= dividend` and `divisor > dividend`.
+            cases.push((a, b));
+        }
+    }
+
+    for (a, b) in cases {
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(to_biguint(q), to_biguint(a) / to_biguint(b));
+        assert_eq!(to_biguint(r), to_biguint(a) % to_biguint(b));
+    }
+}
+
+// Test from_str_radix and to_str_radix against BigUint
+fn biginteger_str_radix_test<B: BigInteger>() {
+    let mut rng = ark_std::test_rng();
+    let to_biguint = |x: B| -> BigUint { x.into() };
+
+    for radix in 2..=16 {
+        for _ in 0..10 {
+            let x = B::rand(&mut rng);
+            let s = x.to_str_radix(radix);
+            assert_eq!(s, to_biguint(x).to_str_radix(radix));
+            assert_eq!(B::from_str_radix(&s, radix), Some(x));
+            assert_eq!(B::from_str_radix(&s.to_uppercase(), radix), Some(x));
+        }
+        assert_eq!(B::from(0u64).to_str_radix(radix), "0");
+        assert_eq!(B::from_str_radix("0", radix), Some(B::from(0u64)));
+        assert_eq!(B::from_str_radix("", radix), None);
+        assert_eq!(B::from_str_radix("-1", radix), None);
+        let invalid_digit = char::from_digit(radix, 17).unwrap();
+        assert_eq!(B::from_str_radix(&invalid_digit.to_string(), radix), None);
+
+        // The maximum value round-trips, and anything larger overflows.
+        let mut max = B::from(0u64);
+        max.sub_with_borrow(&B::from(1u64));
+        let max_str = max.to_str_radix(radix);
+        assert_eq!(B::from_str_radix(&max_str, radix), Some(max));
+        let too_large = (to_biguint(max) + 1u8).to_str_radix(radix);
+        assert_eq!(B::from_str_radix(&too_large, radix), None);
+    }
+}
+
 // Wrapper test function for BigInteger
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = ark_std::test_rng();
@@ -93,6 +257,9 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_arithmetic_test(a, b, zero);
     biginteger_bits_test::<B>();
     biginteger_conversion_test::<B>();
+    biginteger_gcd_test::<B>();
+    biginteger_str_radix_test::<B>();
+    biginteger_div_rem_test::<B>();
 }
 
 #[test]
@@ -136,3 +303,17 @@ fn test_biginteger832() {
     use crate::biginteger::BigInteger832 as B;
     test_biginteger(B::new([0u64; 13]));
 }
+
+#[test]
+#[should_panic]
+fn test_biginteger_set_bit_out_of_range() {
+    use crate::biginteger::BigInteger256 as B;
+    B::new([0u64; 4]).set_bit(256, true);
+}
+
+#[test]
+#[should_panic]
+fn test_biginteger_get_bit_out_of_range() {
+    use crate::biginteger::BigInteger256 as B;
+    B::new([0u64; 4]).get_bit(256);
+}
@@ -0,0 +1,161 @@
+use crate::fields::Field;
+use ark_std::{hash::Hasher, marker::PhantomData, vec, vec::Vec};
+
+/// A Bloom filter over field elements, for fast probabilistic membership
+/// checks against large sets.
+///
+/// [`Self::might_contain`] never returns `false` for an inserted element,
+/// but may return `true` for an element that was never inserted. With `m`
+/// bits, `k` hash functions and `n` inserted elements, this happens with
+/// probability about `(1 - e^(-k * n / m))^k`.
+///
+/// ```
+/// # use ark_ff::FieldBloomFilter;
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let mut filter = FieldBloomFilter::<F>::new(1 << 10, 7);
+/// filter.insert(&F::from(5u64));
+/// assert!(filter.might_contain(&F::from(5u64)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldBloomFilter<F: Field> {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: u32,
+    _field: PhantomData<F>,
+}
+
+impl<F: Field> FieldBloomFilter<F> {
+    /// Creates an empty filter with `num_bits` bits and `num_hashes` hash
+    /// functions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` or `num_hashes` is zero.
+    pub fn new(num_bits: usize, num_hashes: u32) -> Self {
+        assert!(num_bits > 0, "`num_bits` must be positive");
+        assert!(num_hashes > 0, "`num_hashes` must be positive");
+        Self {
+            bits: vec![0; (num_bits + 63) / 64],
+            num_bits,
+            num_hashes,
+            _field: PhantomData,
+        }
+    }
+
+    /// Returns the number of bits of the filter.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Returns the number of hash functions of the filter.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Adds `f` to the set.
+    pub fn insert(&mut self, f: &F) {
+        for seed in 0..self.num_hashes {
+            let i = self.bit_index(f, seed);
+            self.bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    /// Returns `false` if `f` was definitely not inserted, and `true` if it
+    /// may have been.
+    pub fn might_contain(&self, f: &F) -> bool {
+        (0..self.num_hashes).all(|seed| {
+            let i = self.bit_index(f, seed);
+            self.bits[i / 64] & (1 << (i % 64)) != 0
+        })
+    }
+
+    fn bit_index(&self, f: &F, seed: u32) -> usize {
+        let mut hasher = SeededHasher::new(seed);
+        f.hash(&mut hasher);
+        (hasher.finish() % self.num_bits as u64) as usize
+    }
+}
+
+/// A 64-bit FNV-1a hasher whose initial state depends on a seed, with a
+/// final avalanche step so that all output bits depend on the input.
+struct SeededHasher(u64);
+
+impl SeededHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new(seed: u32) -> Self {
+        Self(Self::OFFSET_BASIS ^ mix(u64::from(seed)))
+    }
+}
+
+impl Hasher for SeededHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        mix(self.0)
+    }
+}
+
+/// The finalizer of SplitMix64.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, vec::Vec};
+    // Import through `ark_test_curves` so that `Fr` implements the same `Field` trait.
+    use ark_test_curves::{
+        bls12_381::{Fq2, Fr},
+        FieldBloomFilter, UniformRand,
+    };
+
+    #[test]
+    fn test_bloom_filter_inserted() {
+        let mut rng = test_rng();
+        let mut filter = FieldBloomFilter::<Fr>::new(1000, 5);
+        let elements: Vec<Fr> = (0..500).map(|_| Fr::rand(&mut rng)).collect();
+        for f in &elements {
+            filter.insert(f);
+        }
+        assert!(elements.iter().all(|f| filter.might_contain(f)));
+
+        let mut filter = FieldBloomFilter::<Fq2>::new(64, 3);
+        let f = Fq2::rand(&mut rng);
+        assert!(!filter.might_contain(&f));
+        filter.insert(&f);
+        assert!(filter.might_contain(&f));
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_rate() {
+        let mut rng = test_rng();
+        // With 10 bits per element and 7 hash functions, the expected false
+        // positive rate is about 0.8%.
+        let n = 1000;
+        let mut filter = FieldBloomFilter::<Fr>::new(10 * n, 7);
+        for _ in 0..n {
+            filter.insert(&Fr::rand(&mut rng));
+        }
+        let trials = 10_000;
+        let false_positives = (0..trials)
+            .filter(|_| filter.might_contain(&Fr::rand(&mut rng)))
+            .count();
+        assert!(false_positives < trials * 2 / 100, "{}", false_positives);
+    }
+
+    #[test]
+    #[should_panic(expected = "`num_bits` must be positive")]
+    fn test_bloom_filter_empty() {
+        FieldBloomFilter::<Fr>::new(0, 1);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::fields::PrimeField;
+
+/// An incremental version of [`PrimeField::from_le_bytes_mod_order`], for
+/// inputs that arrive in chunks.
+///
+/// The bytes passed to [`Self::update`] are interpreted as one little-endian
+/// integer, which is reduced modulo the field characteristic as it is read,
+/// so the input never needs to be buffered.
+///
+/// ```
+/// # use ark_ff::{ByteFolder, PrimeField};
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let data = [7u8; 100];
+/// let mut folder = ByteFolder::<F>::new();
+/// folder.update(&data[..30]);
+/// folder.update(&data[30..]);
+/// assert_eq!(folder.finalize(), F::from_le_bytes_mod_order(&data));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByteFolder<F: PrimeField> {
+    /// The reduction of all complete 8-byte limbs read so far.
+    acc: F,
+    /// `2^(64 * l)`, where `l` is the number of complete limbs read so far.
+    scale: F,
+    /// The bytes of the current incomplete limb.
+    limb: u64,
+    /// The number of bytes in `limb`.
+    limb_len: u32,
+}
+
+impl<F: PrimeField> ByteFolder<F> {
+    /// Creates an accumulator that has not read any bytes.
+    pub fn new() -> Self {
+        Self {
+            acc: F::zero(),
+            scale: F::one(),
+            limb: 0,
+            limb_len: 0,
+        }
+    }
+
+    /// Appends `bytes` to the input, as its most significant bytes so far.
+    pub fn update(&mut self, bytes: &[u8]) {
+        let window_size = F::from(1u128 << 64);
+        for byte in bytes {
+            self.limb |= u64::from(*byte) << (8 * self.limb_len);
+            self.limb_len += 1;
+            if self.limb_len == 8 {
+                self.acc += self.scale * F::from(self.limb);
+                self.scale *= window_size;
+                self.limb = 0;
+                self.limb_len = 0;
+            }
+        }
+    }
+
+    /// Returns the input read so far, reduced modulo the field characteristic.
+    pub fn finalize(self) -> F {
+        self.acc + self.scale * F::from(self.limb)
+    }
+}
+
+impl<F: PrimeField> Default for ByteFolder<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{rand::Rng, test_rng, vec::Vec};
+    // Import through `ark_test_curves` so that `Fr` implements the same `Field` trait.
+    use ark_test_curves::{
+        bls12_381::{Fq, Fr},
+        ByteFolder, PrimeField,
+    };
+
+    fn check<F: PrimeField>(data: &[u8], rng: &mut impl Rng) {
+        let expected = F::from_le_bytes_mod_order(data);
+
+        let mut folder = ByteFolder::<F>::new();
+        folder.update(data);
+        assert_eq!(folder.finalize(), expected);
+
+        let mut folder = ByteFolder::<F>::default();
+        let mut rest = data;
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(rng.gen_range(0..=rest.len().min(20)));
+            folder.update(chunk);
+            rest = tail;
+        }
+        assert_eq!(folder.finalize(), expected);
+    }
+
+    #[test]
+    fn test_byte_folder() {
+        let mut rng = test_rng();
+        for len in [0, 1, 7, 8, 9, 31, 32, 33, 48, 100, 1000] {
+            let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            check::<Fr>(&data, &mut rng);
+            check::<Fq>(&data, &mut rng);
+        }
+        check::<Fr>(&[0xff; 1000], &mut rng);
+        assert_eq!(ByteFolder::<Fr>::new().finalize(), Fr::from(0u8));
+    }
+}
@@ -0,0 +1,157 @@
+//! Helpers for selecting field elements without branching on secret data.
+
+use crate::fields::Field;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Returns whether `x` is zero, without branching on it.
+pub(crate) fn ct_is_zero<F: Field>(x: &F) -> Choice {
+    x.ct_eq(&F::zero())
+}
+
+/// Returns `table[index]`, reading every entry of `table` so that the
+/// memory access pattern and running time do not depend on `index`.
+///
+/// # Panics
+///
+/// Panics if `index >= table.len()`.
+///
+/// ```
+/// # use ark_ff::ct_lookup;
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let table = [F::from(0u8), F::from(3u8), F::from(6u8)];
+/// assert_eq!(ct_lookup(&table, 2), F::from(6u8));
+/// ```
+pub fn ct_lookup<F: Field>(table: &[F], index: usize) -> F {
+    assert!(index < table.len(), "index out of bounds");
+    let mut result = F::zero();
+    for (i, entry) in table.iter().enumerate() {
+        let selected = F::from((i as u64).ct_eq(&(index as u64)).unwrap_u8());
+        result += *entry * selected;
+    }
+    result
+}
+
+/// Returns `table[|index|]`, negated if `index` is negative, without
+/// branching on `index`. The table is expected to hold the nonnegative
+/// multiples `[0, P, 2P, ...]`, as in a signed-window (wNAF) selection.
+///
+/// Every entry of `table` is read, so that the memory access pattern and
+/// running time do not depend on `index`.
+///
+/// # Panics
+///
+/// Panics if `|index| >= table.len()`.
+///
+/// ```
+/// # use ark_ff::ct_lookup_signed;
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let table = [F::from(0u8), F::from(3u8), F::from(6u8)];
+/// assert_eq!(ct_lookup_signed(&table, -2), -F::from(6u8));
+/// ```
+pub fn ct_lookup_signed<F: Field>(table: &[F], index: i8) -> F {
+    let index = i16::from(index);
+    // `sign` is `-1` if `index` is negative, and `0` otherwise.
+    let sign = index >> 15;
+    let abs = ((index ^ sign) - sign) as usize;
+    let is_negative = (sign & 1) as u64;
+
+    let result = ct_lookup(table, abs);
+    // result - 2 * result * is_negative
+    result - result.double() * F::from(is_negative)
+}
+
+/// Returns the common value of the elements of `v` if they are all equal, and
+/// `None` otherwise or if `v` is empty.
+///
+/// Unlike [`crate::all_equal`], every element of `v` is examined, so that the
+/// running time does not depend on where a mismatch occurs.
+pub fn ct_all_equal<F: Field>(v: &[F]) -> Option<F> {
+    let (first, rest) = v.split_first()?;
+    let equal = rest
+        .iter()
+        .fold(Choice::from(1), |acc, x| acc & x.ct_eq(first));
+    bool::from(equal).then(|| *first)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{test_rng, vec::Vec};
+    // Import through `ark_test_curves` so that `Fr` implements the same `Field` trait.
+    use ark_test_curves::{
+        bls12_381::{Fq2, Fr},
+        ct_all_equal, ct_lookup, ct_lookup_signed, One, UniformRand,
+    };
+
+    #[test]
+    fn test_ct_lookup() {
+        let mut rng = test_rng();
+        for len in [1, 2, 16] {
+            let table = (0..len).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            for (i, entry) in table.iter().enumerate() {
+                assert_eq!(ct_lookup(&table, i), *entry, "index: {}", i);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ct_lookup_out_of_bounds() {
+        let table = [Fr::from(0u8), Fr::from(1u8)];
+        let _ = ct_lookup(&table, 2);
+    }
+
+    #[test]
+    fn test_ct_lookup_signed() {
+        let mut rng = test_rng();
+        // Random entries, so that no index can match by accident.
+        let table = (0..16).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        for index in -15i8..=15 {
+            let entry = table[index.unsigned_abs() as usize];
+            let expected = if index < 0 { -entry } else { entry };
+            assert_eq!(
+                ct_lookup_signed(&table, index),
+                expected,
+                "index: {}",
+                index
+            );
+        }
+
+        // Both ends of the `i8` range.
+        let table = (0..=128).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        assert_eq!(ct_lookup_signed(&table, i8::MAX), table[127]);
+        assert_eq!(ct_lookup_signed(&table, i8::MIN), -table[128]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ct_lookup_signed_out_of_bounds() {
+        let table = [Fr::from(0u8), Fr::from(1u8)];
+        let _ = ct_lookup_signed(&table, -2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ct_lookup_signed_positive_out_of_bounds() {
+        let table = [Fr::from(0u8), Fr::from(1u8)];
+        let _ = ct_lookup_signed(&table, 2);
+    }
+
+    #[test]
+    fn test_ct_all_equal() {
+        let mut rng = test_rng();
+        let a = Fr::rand(&mut rng);
+        assert_eq!(ct_all_equal(&[a; 10]), Some(a));
+        assert_eq!(ct_all_equal(&[a]), Some(a));
+        assert_eq!(ct_all_equal::<Fr>(&[]), None);
+
+        for i in 1..10 {
+            let mut v = [a; 10];
+            v[i] += Fr::one();
+            assert_eq!(ct_all_equal(&v), None);
+        }
+
+        let b = Fq2::rand(&mut rng);
+        assert_eq!(ct_all_equal(&[b; 3]), Some(b));
+        assert_eq!(ct_all_equal(&[b, b, b + Fq2::one()]), None);
+    }
+}
@@ -78,7 +78,16 @@ fn do_test(data: ExpanderVector) -> Result<(), Failed> {
         _ => unimplemented!(),
     };
     let exp = get_expander(exp_id, data.dst.as_bytes(), data.k);
+    let dst_prime = exp.construct_dst_prime();
     for v in data.vectors.iter() {
+        if dst_prime != hex::decode(&v.dst_prime).unwrap() {
+            return Err(format!(
+                "Expander: {}\nDST_prime mismatch for DST of {} bytes",
+                data.hash,
+                data.dst.len(),
+            )
+            .into());
+        }
         let len = usize::from_str_radix(v.len_in_bytes.trim_start_matches("0x"), 16).unwrap();
         let got = exp.expand(v.msg.as_bytes(), len);
         let want = hex::decode(&v.uniform_bytes).unwrap();
@@ -128,3 +137,156 @@ fn get_expander(id: ExpID, _dst: &[u8], k: usize) -> Box<dyn Expander> {
         },
     }
 }
+
+#[test]
+fn xof_field_hasher() {
+    use ark_test_curves::{
+        bls12_381::{Fq, Fq2},
+        fields::field_hashers::{HashToField, XofFieldHasher},
+        Field, PrimeField,
+    };
+    use num_bigint::BigUint;
+
+    // ceil((381 + 128) / 8)
+    let len_per_elem = 64;
+    let modulus: BigUint = Fq::MODULUS.into();
+
+    // Both the short and the oversize DST vectors of RFC 9380, reduced independently of the
+    // field's own byte conversion.
+    for name in [
+        "expand_message_xof_SHAKE128_36",
+        "expand_message_xof_SHAKE128_256",
+    ] {
+        let path = format!("./src/fields/field_hashers/expander/testdata/{}.json", name);
+        let data: ExpanderVector =
+            serde_json::from_reader(BufReader::new(File::open(path).unwrap())).unwrap();
+        let dst = data.dst.as_bytes();
+
+        for v in data.vectors.iter().filter(|v| v.len_in_bytes == "0x80") {
+            let msg = v.msg.as_bytes();
+            let want = hex::decode(&v.uniform_bytes)
+                .unwrap()
+                .chunks(len_per_elem)
+                .map(|chunk| Fq::from(BigUint::from_bytes_be(chunk) % &modulus))
+                .collect::<Vec<_>>();
+
+            let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(dst);
+            let got: Vec<Fq> = hasher.hash_to_field(msg, 2);
+            assert_eq!(got, want, "{}: {:?}", name, v.msg);
+
+            let hasher = <XofFieldHasher<Shake128> as HashToField<Fq2>>::new(dst);
+            let got: Vec<Fq2> = hasher.hash_to_field(msg, 1);
+            assert_eq!(
+                got,
+                vec![Fq2::from_base_prime_field_elems(&want).unwrap()],
+                "{}: {:?}",
+                name,
+                v.msg
+            );
+        }
+    }
+}
+
+#[test]
+fn hash_to_field_many_rfc_vectors() {
+    use ark_test_curves::{
+        bls12_381::Fq,
+        fields::field_hashers::{DefaultFieldHasher, HashToField},
+        PrimeField,
+    };
+
+    // The `u` values of the BLS12381G1_XMD:SHA-256_SSWU_RO_ suite of RFC 9380.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let vectors: [(&[u8], [&str; 2]); 2] = [
+        (b"", [
+            "0x0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+            "0x019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+        ]),
+        (b"abc", [
+            "0x0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+            "0x003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139",
+        ]),
+    ];
+    for (msg, u) in vectors {
+        let got: Vec<Fq> = DefaultFieldHasher::<Sha256>::hash_to_field_many(msg, dst, 2);
+        let want: Vec<Fq> = u.iter().map(|u| Fq::from_hex_string(u).unwrap()).collect();
+        assert_eq!(got, want);
+    }
+}
+
+#[test]
+fn oversize_dst_field_hasher() {
+    use ark_test_curves::{
+        bls12_381::Fq,
+        fields::field_hashers::{DefaultFieldHasher, HashToField, XofFieldHasher},
+    };
+    use sha2::Digest;
+    use sha3::digest::{ExtendableOutput, Update};
+
+    let long_dst = [0x42u8; 256];
+    let msg = b"abc";
+
+    // An oversize DST must behave exactly like its hash `H("H2C-OVERSIZE-DST-" || DST)`.
+    let hashed_dst = Sha256::new()
+        .chain_update(b"H2C-OVERSIZE-DST-")
+        .chain_update(long_dst)
+        .finalize();
+    let got: Vec<Fq> =
+        <DefaultFieldHasher<Sha256> as HashToField<Fq>>::new(&long_dst).hash_to_field(msg, 2);
+    let want: Vec<Fq> =
+        <DefaultFieldHasher<Sha256> as HashToField<Fq>>::new(&hashed_dst).hash_to_field(msg, 2);
+    assert_eq!(got, want);
+
+    let mut xofer = Shake128::default();
+    xofer.update(b"H2C-OVERSIZE-DST-");
+    xofer.update(&long_dst);
+    let hashed_dst = xofer.finalize_boxed(32);
+    let got: Vec<Fq> =
+        <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&long_dst).hash_to_field(msg, 2);
+    let want: Vec<Fq> =
+        <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&hashed_dst).hash_to_field(msg, 2);
+    assert_eq!(got, want);
+
+    // A DST of exactly 255 bytes is used as is.
+    let dst = [0x42u8; 255];
+    let expander = ExpanderXmd {
+        hasher: Sha256::default(),
+        dst: dst.to_vec(),
+        block_size: 64,
+    };
+    assert_eq!(expander.construct_dst_prime(), [&dst[..], &[255]].concat());
+}
+
+#[test]
+fn hash_field_elements() {
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{
+        bls12_381::Fr,
+        fields::field_hashers::{hash_field_elements, DefaultFieldHasher},
+    };
+    type Hasher = DefaultFieldHasher<Sha256>;
+
+    let mut rng = test_rng();
+    let elements: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut rng)).collect();
+    let digest = hash_field_elements::<Fr, Hasher>(&elements, b"leaf");
+    assert_eq!(
+        digest,
+        hash_field_elements::<Fr, Hasher>(&elements, b"leaf")
+    );
+
+    // Permuting, truncating or changing the domain changes the output.
+    let mut swapped = elements.clone();
+    swapped.swap(0, 1);
+    assert_ne!(digest, hash_field_elements::<Fr, Hasher>(&swapped, b"leaf"));
+    let mut rotated = elements.clone();
+    rotated.rotate_left(1);
+    assert_ne!(digest, hash_field_elements::<Fr, Hasher>(&rotated, b"leaf"));
+    assert_ne!(
+        digest,
+        hash_field_elements::<Fr, Hasher>(&elements[..3], b"leaf")
+    );
+    assert_ne!(
+        digest,
+        hash_field_elements::<Fr, Hasher>(&elements, b"node")
+    );
+}
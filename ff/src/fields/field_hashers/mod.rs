@@ -1,12 +1,14 @@
 mod expander;
+mod poseidon;
 
 use crate::{Field, PrimeField};
 
 use ark_std::vec::Vec;
-use digest::DynDigest;
+use digest::{DynDigest, ExtendableOutput, Update};
 use expander::Expander;
 
-use self::expander::ExpanderXmd;
+use self::expander::{ExpanderXmd, ExpanderXof};
+pub use self::poseidon::{PoseidonConfig, PoseidonFieldHasher};
 
 /// Trait for hashing messages to field elements.
 pub trait HashToField<F: Field>: Sized {
@@ -15,10 +17,20 @@ pub trait HashToField<F: Field>: Sized {
     /// # Arguments
     ///
     /// * `domain` - bytes that get concatenated with the `msg` during hashing, in order to separate potentially interfering instantiations of the hasher.
+    ///   Domains longer than 255 bytes are first hashed down to `H("H2C-OVERSIZE-DST-" || domain)`, as mandated by RFC 9380.
     fn new(domain: &[u8]) -> Self;
 
     /// Hash an arbitrary `msg` to #`count` elements from field `F`.
     fn hash_to_field(&self, msg: &[u8], count: usize) -> Vec<F>;
+
+    /// Hashes `msg` to `count` independent elements of `F` under the domain
+    /// `dst`, as done by `hash_to_field(msg, count)` in RFC 9380 for the
+    /// hashers that implement it.
+    ///
+    /// This is a shorthand for `Self::new(dst).hash_to_field(msg, count)`.
+    fn hash_to_field_many(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+        Self::new(dst).hash_to_field(msg, count)
+    }
 }
 
 /// This field hasher constructs a Hash-To-Field based on a fixed-output hash function,
@@ -63,32 +75,128 @@ impl<F: Field, H: Default + DynDigest + Clone, const SEC_PARAM: usize> HashToFie
     }
 
     fn hash_to_field(&self, message: &[u8], count: usize) -> Vec<F> {
-        let m = F::extension_degree() as usize;
-
-        // The user imposes a `count` of elements of F_p^m to output per input msg,
-        // each field element comprising `m` BasePrimeField elements.
-        let len_in_bytes = count * m * self.len_per_base_elem;
-        let uniform_bytes = self.expander.expand(message, len_in_bytes);
-
-        let mut output = Vec::with_capacity(count);
-        let mut base_prime_field_elems = Vec::with_capacity(m);
-        for i in 0..count {
-            base_prime_field_elems.clear();
-            for j in 0..m {
-                let elm_offset = self.len_per_base_elem * (j + i * m);
-                let val = F::BasePrimeField::from_be_bytes_mod_order(
-                    &uniform_bytes[elm_offset..][..self.len_per_base_elem],
-                );
-                base_prime_field_elems.push(val);
-            }
-            let f = F::from_base_prime_field_elems(&base_prime_field_elems).unwrap();
-            output.push(f);
+        hash_to_field_with_expander(&self.expander, self.len_per_base_elem, message, count)
+    }
+}
+
+/// This field hasher constructs a Hash-To-Field based on an extendable-output
+/// function (XOF), like SHAKE128 or SHAKE256, using `expand_message_xof`.
+/// The implementation aims to follow the specification in [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+///
+/// # Examples
+///
+/// ```
+/// use ark_test_curves::bls12_381::Fq;
+/// use ark_ff::fields::field_hashers::{HashToField, XofFieldHasher};
+/// use sha3::Shake128;
+///
+/// let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&[1, 2, 3]);
+/// let field_elements: Vec<Fq> = hasher.hash_to_field(b"Hello, World!", 2);
+///
+/// assert_eq!(field_elements.len(), 2);
+/// ```
+pub struct XofFieldHasher<
+    H: Default + Update + Clone + ExtendableOutput,
+    const SEC_PARAM: usize = 128,
+> {
+    expander: ExpanderXof<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: Default + Update + Clone + ExtendableOutput, const SEC_PARAM: usize>
+    HashToField<F> for XofFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        let len_per_base_elem = get_len_per_elem::<F, SEC_PARAM>();
+
+        let expander = ExpanderXof {
+            xofer: H::default(),
+            dst: dst.to_vec(),
+            k: SEC_PARAM,
+        };
+
+        XofFieldHasher {
+            expander,
+            len_per_base_elem,
         }
+    }
 
-        output
+    fn hash_to_field(&self, message: &[u8], count: usize) -> Vec<F> {
+        hash_to_field_with_expander(&self.expander, self.len_per_base_elem, message, count)
     }
 }
 
+/// A [`XofFieldHasher`] based on SHAKE128.
+#[cfg(feature = "shake")]
+pub type Shake128FieldHasher<const SEC_PARAM: usize = 128> =
+    XofFieldHasher<sha3::Shake128, SEC_PARAM>;
+
+/// A [`XofFieldHasher`] based on SHAKE256.
+#[cfg(feature = "shake")]
+pub type Shake256FieldHasher<const SEC_PARAM: usize = 128> =
+    XofFieldHasher<sha3::Shake256, SEC_PARAM>;
+
+/// Hashes a vector of field elements to a single field element with the
+/// hasher `H`, e.g. to compute Merkle tree leaves.
+///
+/// The canonical encodings of the elements, which all have the same length,
+/// are concatenated and hashed to one element of `F` under the domain `dst`.
+///
+/// # Examples
+///
+/// ```
+/// use ark_test_curves::bls12_381::Fr;
+/// use ark_ff::fields::field_hashers::{hash_field_elements, DefaultFieldHasher};
+/// use sha2::Sha256;
+///
+/// let leaf = [Fr::from(1u64), Fr::from(2u64)];
+/// let digest = hash_field_elements::<Fr, DefaultFieldHasher<Sha256>>(&leaf, b"leaf");
+/// assert_eq!(digest, hash_field_elements::<Fr, DefaultFieldHasher<Sha256>>(&leaf, b"leaf"));
+/// ```
+pub fn hash_field_elements<F: PrimeField, H: HashToField<F>>(elements: &[F], dst: &[u8]) -> F {
+    let mut message = Vec::with_capacity(elements.len() * F::zero().compressed_size());
+    for element in elements {
+        element
+            .serialize_compressed(&mut message)
+            .expect("serializing to a vector never fails");
+    }
+    H::new(dst).hash_to_field(&message, 1)[0]
+}
+
+/// Expands `message` into uniform bytes with `expander`, and maps them to
+/// `count` elements of `F`, each base prime field element consuming
+/// `len_per_base_elem` bytes.
+fn hash_to_field_with_expander<F: Field>(
+    expander: &impl Expander,
+    len_per_base_elem: usize,
+    message: &[u8],
+    count: usize,
+) -> Vec<F> {
+    let m = F::extension_degree() as usize;
+
+    // The user imposes a `count` of elements of F_p^m to output per input msg,
+    // each field element comprising `m` BasePrimeField elements.
+    let len_in_bytes = count * m * len_per_base_elem;
+    let uniform_bytes = expander.expand(message, len_in_bytes);
+
+    let mut output = Vec::with_capacity(count);
+    let mut base_prime_field_elems = Vec::with_capacity(m);
+    for i in 0..count {
+        base_prime_field_elems.clear();
+        for j in 0..m {
+            let elm_offset = len_per_base_elem * (j + i * m);
+            let val = F::BasePrimeField::from_be_bytes_mod_order(
+                &uniform_bytes[elm_offset..][..len_per_base_elem],
+            );
+            base_prime_field_elems.push(val);
+        }
+        let f = F::from_base_prime_field_elems(&base_prime_field_elems).unwrap();
+        output.push(f);
+    }
+
+    output
+}
+
 /// This function computes the length in bytes that a hash function should output
 /// for hashing an element of type `Field`.
 /// See section 5.1 and 5.3 of the
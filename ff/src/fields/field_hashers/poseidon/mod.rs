@@ -0,0 +1,169 @@
+use crate::PrimeField;
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+
+use super::HashToField;
+
+/// The parameters of a Poseidon permutation over the prime field `F`.
+///
+/// The permutation acts on a state of `RATE + CAPACITY` field elements, and
+/// consists of `FULL_ROUNDS / 2` full rounds, followed by `PARTIAL_ROUNDS`
+/// partial rounds, followed by another `FULL_ROUNDS / 2` full rounds. Every
+/// round adds the round constants, applies the S-box `x^ALPHA` (to every state
+/// element in full rounds, and to the first one in partial rounds), and then
+/// multiplies the state by the MDS matrix.
+pub trait PoseidonConfig<F: PrimeField>: 'static + Send + Sync + Sized {
+    /// The number of full rounds. Must be even.
+    const FULL_ROUNDS: usize;
+    /// The number of partial rounds.
+    const PARTIAL_ROUNDS: usize;
+    /// The exponent of the S-box `x^ALPHA`.
+    const ALPHA: u64;
+    /// The number of state elements that absorb input and squeeze output.
+    const RATE: usize;
+    /// The number of state elements that are never directly touched by input
+    /// or output.
+    const CAPACITY: usize;
+
+    /// Returns the MDS matrix, of size `(RATE + CAPACITY) x (RATE + CAPACITY)`.
+    fn mds() -> Vec<Vec<F>>;
+
+    /// Returns the round constants, with one row of `RATE + CAPACITY`
+    /// elements per round.
+    fn round_constants() -> Vec<Vec<F>>;
+}
+
+/// This field hasher constructs a Hash-To-Field based on the Poseidon
+/// permutation with parameters `P`, used as a sponge.
+///
+/// Unlike the byte-oriented hashers, the same hash can be computed
+/// efficiently inside an arithmetic circuit over `F`. Inputs that are already
+/// field elements can be hashed directly with [`PoseidonFieldHasher::hash`].
+pub struct PoseidonFieldHasher<F: PrimeField, P: PoseidonConfig<F>> {
+    mds: Vec<Vec<F>>,
+    round_constants: Vec<Vec<F>>,
+    domain_tag: F,
+    _config: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: PoseidonConfig<F>> PoseidonFieldHasher<F, P> {
+    const WIDTH: usize = P::RATE + P::CAPACITY;
+
+    fn with_domain_tag(domain_tag: F) -> Self {
+        assert!(P::RATE > 0 && P::CAPACITY > 0);
+        assert_eq!(
+            P::FULL_ROUNDS % 2,
+            0,
+            "the number of full rounds must be even"
+        );
+        let mds = P::mds();
+        assert!(mds.len() == Self::WIDTH && mds.iter().all(|row| row.len() == Self::WIDTH));
+        let round_constants = P::round_constants();
+        assert!(
+            round_constants.len() == P::FULL_ROUNDS + P::PARTIAL_ROUNDS
+                && round_constants.iter().all(|row| row.len() == Self::WIDTH)
+        );
+
+        Self {
+            mds,
+            round_constants,
+            domain_tag,
+            _config: PhantomData,
+        }
+    }
+
+    /// Applies the Poseidon permutation to `state`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state.len() != P::RATE + P::CAPACITY`.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), Self::WIDTH);
+        let half_full_rounds = P::FULL_ROUNDS / 2;
+        let mut new_state = vec![F::zero(); Self::WIDTH];
+
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (s, c) in state.iter_mut().zip(constants) {
+                *s += c;
+            }
+
+            let is_full_round =
+                round < half_full_rounds || round >= half_full_rounds + P::PARTIAL_ROUNDS;
+            if is_full_round {
+                for s in state.iter_mut() {
+                    *s = s.pow([P::ALPHA]);
+                }
+            } else {
+                state[0] = state[0].pow([P::ALPHA]);
+            }
+
+            for (new, row) in new_state.iter_mut().zip(&self.mds) {
+                *new = row.iter().zip(state.iter()).map(|(m, s)| *m * s).sum();
+            }
+            state.copy_from_slice(&new_state);
+        }
+    }
+
+    /// Hashes the field elements in `inputs` to `count` field elements.
+    ///
+    /// The inputs are padded with a one followed by zeros up to a multiple of
+    /// `P::RATE`, and absorbed into a sponge whose first capacity element is
+    /// initialised with the domain separation tag.
+    pub fn hash(&self, inputs: &[F], count: usize) -> Vec<F> {
+        let mut state = vec![F::zero(); Self::WIDTH];
+        state[0] = self.domain_tag;
+
+        let padding_len = P::RATE - inputs.len() % P::RATE;
+        let mut padded = inputs.to_vec();
+        padded.push(F::one());
+        padded.resize(inputs.len() + padding_len, F::zero());
+
+        for chunk in padded.chunks(P::RATE) {
+            for (s, x) in state[P::CAPACITY..].iter_mut().zip(chunk) {
+                *s += x;
+            }
+            self.permute(&mut state);
+        }
+
+        let mut output = Vec::with_capacity(count);
+        loop {
+            output.extend(state[P::CAPACITY..].iter().take(count - output.len()));
+            if output.len() == count {
+                return output;
+            }
+            self.permute(&mut state);
+        }
+    }
+}
+
+/// Packs `bytes` into field elements, interpreting every chunk of
+/// `(F::MODULUS_BIT_SIZE - 1) / 8` bytes as a little-endian integer.
+fn bytes_to_field_elements<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    let bytes_per_elem = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+    bytes
+        .chunks(bytes_per_elem)
+        .map(F::from_le_bytes_mod_order)
+        .collect()
+}
+
+impl<F: PrimeField, P: PoseidonConfig<F>> HashToField<F> for PoseidonFieldHasher<F, P> {
+    fn new(domain: &[u8]) -> Self {
+        // The domain separation tag is the hash of `domain` under the zero tag.
+        let hasher = Self::with_domain_tag(F::zero());
+        let domain_tag = hasher.hash(&bytes_to_field_elements(domain), 1)[0];
+        Self {
+            domain_tag,
+            ..hasher
+        }
+    }
+
+    fn hash_to_field(&self, message: &[u8], count: usize) -> Vec<F> {
+        // Prefix the byte length so that messages differing only in trailing
+        // zero bytes are packed into distinct inputs.
+        let mut inputs = vec![F::from(message.len() as u64)];
+        inputs.extend(bytes_to_field_elements::<F>(message));
+        self.hash(&inputs, count)
+    }
+}
+
+#[cfg(test)]
+mod tests;
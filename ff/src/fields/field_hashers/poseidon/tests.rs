@@ -0,0 +1,159 @@
+use ark_std::{vec, vec::Vec};
+// Import through `ark_test_curves` so that `Fr` implements the same `PrimeField` trait.
+use ark_test_curves::{
+    bls12_381::Fr,
+    fields::field_hashers::{HashToField, PoseidonConfig, PoseidonFieldHasher},
+    BigInteger, Field, MontFp, PrimeField,
+};
+
+/// The Grain LFSR used by the reference implementation to generate Poseidon
+/// parameters, see <https://extgit.iaik.tugraz.at/krypto/hadeshash>.
+struct GrainLfsr {
+    state: Vec<bool>,
+}
+
+impl GrainLfsr {
+    fn new(field_bits: u64, width: u64, full_rounds: u64, partial_rounds: u64) -> Self {
+        let mut state = Vec::with_capacity(80);
+        let mut append = |value: u64, bits: usize| {
+            state.extend((0..bits).rev().map(|i| (value >> i) & 1 == 1));
+        };
+        // Prime field, S-box `x^alpha`.
+        append(1, 2);
+        append(0, 4);
+        append(field_bits, 12);
+        append(width, 12);
+        append(full_rounds, 10);
+        append(partial_rounds, 10);
+        append((1 << 30) - 1, 30);
+
+        let mut lfsr = Self { state };
+        for _ in 0..160 {
+            lfsr.step();
+        }
+        lfsr
+    }
+
+    fn step(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.remove(0);
+        self.state.push(bit);
+        bit
+    }
+
+    fn next_bit(&mut self) -> bool {
+        loop {
+            if self.step() {
+                return self.step();
+            }
+            self.step();
+        }
+    }
+
+    /// Returns `num_bits` bits, most significant first, interpreted as an integer.
+    fn next_bigint(&mut self, num_bits: usize) -> <Fr as PrimeField>::BigInt {
+        let bits = (0..num_bits).map(|_| self.next_bit()).collect::<Vec<_>>();
+        <Fr as PrimeField>::BigInt::from_bits_be(&bits)
+    }
+}
+
+/// The reference parameters for `x^5` over the BLS12-381 scalar field, with a
+/// width of 3, 8 full rounds and 57 partial rounds.
+struct ReferenceConfig;
+
+impl ReferenceConfig {
+    fn generate() -> (Vec<Vec<Fr>>, Vec<Vec<Fr>>) {
+        let n = Fr::MODULUS_BIT_SIZE as usize;
+        let width = Self::RATE + Self::CAPACITY;
+        let num_rounds = Self::FULL_ROUNDS + Self::PARTIAL_ROUNDS;
+        let mut lfsr = GrainLfsr::new(n as u64, width as u64, 8, 57);
+
+        // Round constants are sampled by rejection.
+        let mut constants = Vec::with_capacity(num_rounds * width);
+        while constants.len() < num_rounds * width {
+            if let Some(c) = Fr::from_bigint(lfsr.next_bigint(n)) {
+                constants.push(c);
+            }
+        }
+        let round_constants = constants.chunks(width).map(|row| row.to_vec()).collect();
+
+        // The MDS matrix is the Cauchy matrix `1 / (x_i + y_j)`.
+        let xs_ys = (0..2 * width)
+            .map(|_| Fr::from_le_bytes_mod_order(&lfsr.next_bigint(n).to_bytes_le()))
+            .collect::<Vec<_>>();
+        let (xs, ys) = xs_ys.split_at(width);
+        let mds = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (*x + y).inverse().unwrap()).collect())
+            .collect();
+
+        (mds, round_constants)
+    }
+}
+
+impl PoseidonConfig<Fr> for ReferenceConfig {
+    const FULL_ROUNDS: usize = 8;
+    const PARTIAL_ROUNDS: usize = 57;
+    const ALPHA: u64 = 5;
+    const RATE: usize = 2;
+    const CAPACITY: usize = 1;
+
+    fn mds() -> Vec<Vec<Fr>> {
+        Self::generate().0
+    }
+
+    fn round_constants() -> Vec<Vec<Fr>> {
+        Self::generate().1
+    }
+}
+
+type Hasher = PoseidonFieldHasher<Fr, ReferenceConfig>;
+
+#[test]
+fn test_permutation_known_answer() {
+    // The first round constant and MDS entry of the reference parameters.
+    assert_eq!(
+        ReferenceConfig::round_constants()[0][0],
+        MontFp!("48991097081732275468845314168021420565497297775988823234113406403095118809216")
+    );
+    assert_eq!(
+        ReferenceConfig::mds()[0][0],
+        MontFp!("27854988750630959170337239780597144027224715023811960992659706878268355039181")
+    );
+
+    // The test vector for `poseidonperm_x5_255_3` from the reference implementation.
+    let hasher = <Hasher as HashToField<Fr>>::new(&[]);
+    let mut state = vec![Fr::from(0u8), Fr::from(1u8), Fr::from(2u8)];
+    hasher.permute(&mut state);
+    let expected: [Fr; 3] = [
+        MontFp!("18456658763349757341014058622209659766100673761449600566550821987295786346378"),
+        MontFp!("37068251774887509885063625701815026138353041152735229476479055620962268601796"),
+        MontFp!("26763157702141528937904191329664859174584798817251788852101947537759678822298"),
+    ];
+    assert_eq!(state, expected);
+}
+
+#[test]
+fn test_hash() {
+    let hasher = <Hasher as HashToField<Fr>>::new(b"domain");
+
+    let inputs = [Fr::from(1u8), Fr::from(2u8), Fr::from(3u8)];
+    let output = hasher.hash(&inputs, 5);
+    assert_eq!(output.len(), 5);
+    assert_eq!(hasher.hash(&inputs, 2), output[..2]);
+    // Padding distinguishes inputs that differ only in trailing zeros.
+    assert_ne!(
+        hasher.hash(&inputs[..2], 1),
+        hasher.hash(&[inputs[0], inputs[1], Fr::from(0u8)], 1)
+    );
+
+    let from_bytes: Vec<Fr> = hasher.hash_to_field(b"Hello, World!", 3);
+    assert_eq!(from_bytes.len(), 3);
+    let other: Vec<Fr> = hasher.hash_to_field(b"Hello, World!\0", 3);
+    assert_ne!(from_bytes, other);
+
+    let other_domain = <Hasher as HashToField<Fr>>::new(b"other domain");
+    let other: Vec<Fr> = other_domain.hash_to_field(b"Hello, World!", 3);
+    assert_ne!(from_bytes, other);
+}
@@ -1,20 +1,28 @@
-use crate::{biginteger::BigInteger, fields::utils::k_adicity, UniformRand};
+use crate::{
+    biginteger::BigInteger,
+    fields::utils::{crc32, k_adicity},
+    UniformRand,
+};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
-    CanonicalSerializeWithFlags, EmptyFlags, Flags,
+    CanonicalSerializeWithFlags, Compress, EmptyFlags, Flags, SerializationError, Valid, Validate,
 };
 use ark_std::{
     cmp::min,
-    fmt::{Debug, Display},
+    fmt::{self, Debug, Display},
+    format,
     hash::Hash,
+    io::{Read, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
+    string::{String, ToString},
     vec::Vec,
 };
 
 pub use ark_ff_macros;
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
+use subtle::ConditionallySelectable;
 use zeroize::Zeroize;
 
 pub mod utils;
@@ -28,6 +36,24 @@ pub use self::models::*;
 
 pub mod field_hashers;
 
+pub mod window_table;
+pub use self::window_table::*;
+
+pub mod small_multiple_table;
+pub use self::small_multiple_table::*;
+
+pub mod const_time;
+pub use self::const_time::*;
+
+pub mod byte_folder;
+pub use self::byte_folder::*;
+
+pub mod bloom_filter;
+pub use self::bloom_filter::*;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 #[cfg(feature = "parallel")]
 use ark_std::cmp::max;
 #[cfg(feature = "parallel")]
@@ -137,6 +163,11 @@ pub trait Field:
     + From<u32>
     + From<u16>
     + From<u8>
+    + From<i128>
+    + From<i64>
+    + From<i32>
+    + From<i16>
+    + From<i8>
     + From<bool>
 {
     type BasePrimeField: PrimeField;
@@ -150,6 +181,16 @@ pub trait Field:
     const ZERO: Self;
     /// The multiplicative identity of the field.
     const ONE: Self;
+    /// The additive inverse of the multiplicative identity, i.e. `-1`.
+    ///
+    /// ```
+    /// use ark_ff::{Field, One};
+    /// use ark_test_curves::bls12_381::{Fq12, Fr};
+    ///
+    /// assert_eq!(Fr::NEG_ONE, -Fr::one());
+    /// assert_eq!(Fq12::NEG_ONE, -Fq12::one());
+    /// ```
+    const NEG_ONE: Self;
 
     /// Returns the characteristic of the field,
     /// in little-endian representation.
@@ -157,6 +198,17 @@ pub trait Field:
         Self::BasePrimeField::characteristic()
     }
 
+    /// Returns the characteristic of the field as a [`BigUint`].
+    fn characteristic_biguint() -> BigUint {
+        Self::BasePrimeField::MODULUS.into()
+    }
+
+    /// Returns the characteristic of the field as a big integer, i.e. the
+    /// modulus of `Self::BasePrimeField`.
+    fn characteristic_bigint() -> <Self::BasePrimeField as PrimeField>::BigInt {
+        Self::BasePrimeField::MODULUS
+    }
+
     /// Returns the extension degree of this field with respect
     /// to `Self::BasePrimeField`.
     fn extension_degree() -> u64;
@@ -177,6 +229,44 @@ pub trait Field:
     /// ```
     fn from_base_prime_field(elem: Self::BasePrimeField) -> Self;
 
+    /// Returns whether every base prime field coordinate of `self` is stored
+    /// in its canonical representation, e.g. as a residue smaller than the
+    /// modulus for [`Fp`]. Elements built with unchecked constructors from
+    /// untrusted data may fail this, and then compare unequal to the same
+    /// value in canonical form.
+    ///
+    /// A coordinate is canonical if converting it to an integer and back
+    /// yields an identical representation.
+    fn validate_canonical(&self) -> bool {
+        self.to_base_prime_field_elements()
+            .all(|c| Self::BasePrimeField::from_bigint(c.into_bigint()) == Some(c))
+    }
+
+    /// Returns whether `self == other`, examining every base prime field
+    /// coordinate so that the running time does not depend on where the
+    /// elements differ. Use this when either element is secret.
+    ///
+    /// The default implementation compares the limbs of the canonical
+    /// representations of the base prime field coordinates.
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.to_base_prime_field_elements()
+            .zip(other.to_base_prime_field_elements())
+            .fold(subtle::Choice::from(1), |acc, (a, b)| {
+                acc & subtle::ConstantTimeEq::ct_eq(
+                    a.into_bigint().as_ref(),
+                    b.into_bigint().as_ref(),
+                )
+            })
+    }
+
+    /// Returns whether `self == other`, possibly returning as soon as a
+    /// difference is found. Only use this when both elements are public.
+    ///
+    /// The default implementation uses `==`.
+    fn vartime_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+
     /// Returns `self + self`.
     #[must_use]
     fn double(&self) -> Self;
@@ -187,6 +277,27 @@ pub trait Field:
     /// Negates `self` in place.
     fn neg_in_place(&mut self) -> &mut Self;
 
+    /// Negates `self` in place if `flag` is set, and leaves it unchanged
+    /// otherwise.
+    ///
+    /// The result is selected by masking rather than by branching on `flag`,
+    /// so that the running time does not reveal a secret `flag`.
+    fn conditional_negate(&mut self, flag: bool) {
+        // self - 2 * self * flag
+        *self -= self.double() * Self::from(u64::from(flag));
+    }
+
+    /// Classifies `self`, returning `0` if it is zero, `1` if it is one, and
+    /// `2` otherwise.
+    ///
+    /// Both comparisons use [`Field::ct_eq`], so that the running time does
+    /// not reveal the class of a secret `self`.
+    fn classify_01(&self) -> u8 {
+        let is_zero = const_time::ct_is_zero(self).unwrap_u8();
+        let is_one = self.ct_eq(&Self::one()).unwrap_u8();
+        2 - 2 * is_zero - is_one
+    }
+
     /// Attempt to deserialize a field element. Returns `None` if the
     /// deserialization fails.
     ///
@@ -204,12 +315,61 @@ pub trait Field:
     /// from a hash-function or RNG output.
     fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)>;
 
+    /// Like [`Field::from_random_bytes`], but reads the element from a prefix
+    /// of `bytes` and additionally returns the length of that prefix, e.g. to
+    /// advance a cursor into an RNG or XOF stream when rejection sampling a
+    /// sequence of field elements. Bytes past this count are never read.
+    ///
+    /// Prime fields agree with [`Field::from_random_bytes`]. Extension fields
+    /// in this crate read their coefficients back to back rather than
+    /// splitting `bytes` evenly between them. The default conservatively
+    /// reports all of `bytes` as consumed.
+    fn from_random_bytes_consumed(bytes: &[u8]) -> Option<(Self, usize)> {
+        Self::from_random_bytes(bytes).map(|f| (f, bytes.len()))
+    }
+
+    /// Writes the compressed canonical encoding of `self`, followed by the
+    /// little-endian CRC-32 checksum of that encoding.
+    ///
+    /// This only guards against accidental corruption of stored elements;
+    /// it offers no protection against deliberate tampering.
+    fn serialize_with_checksum<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)?;
+        writer.write_all(&bytes)?;
+        writer.write_all(&crc32(&bytes).to_le_bytes())?;
+        Ok(())
+    }
+
+    /// Reads an element written by [`Field::serialize_with_checksum`].
+    ///
+    /// Returns [`SerializationError::InvalidData`] if the checksum does not
+    /// match the encoding, or if the encoding is not canonical.
+    fn deserialize_with_checksum<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let mut bytes = ark_std::vec![0u8; Self::zero().compressed_size()];
+        reader.read_exact(&mut bytes)?;
+        let mut checksum = [0u8; 4];
+        reader.read_exact(&mut checksum)?;
+        if u32::from_le_bytes(checksum) != crc32(&bytes) {
+            return Err(SerializationError::InvalidData);
+        }
+        Self::deserialize_compressed(&bytes[..])
+    }
+
     /// Returns a `LegendreSymbol`, which indicates whether this field element
     /// is  1 : a quadratic residue
     ///  0 : equal to 0
     /// -1 : a quadratic non-residue
     fn legendre(&self) -> LegendreSymbol;
 
+    /// Returns true if `self` is a square in the field, i.e. if it is zero
+    /// or a quadratic residue.
+    ///
+    /// This is cheaper than checking whether [`Field::sqrt`] succeeds.
+    fn is_square(&self) -> bool {
+        !self.legendre().is_qnr()
+    }
+
     /// Returns the square root of self, if it exists.
     #[must_use]
     fn sqrt(&self) -> Option<Self> {
@@ -219,6 +379,24 @@ pub trait Field:
         }
     }
 
+    /// Returns both square roots of self, if they exist.
+    ///
+    /// The roots are ordered according to the `Ord` implementation of the
+    /// field, with the smaller root first. For prime fields this means the
+    /// root whose `into_bigint()` is smaller comes first. If `self` is zero,
+    /// both roots are zero.
+    #[must_use]
+    fn sqrt_both(&self) -> Option<(Self, Self)> {
+        self.sqrt().map(|root| {
+            let neg_root = -root;
+            if root <= neg_root {
+                (root, neg_root)
+            } else {
+                (neg_root, root)
+            }
+        })
+    }
+
     /// Sets `self` to be the square root of `self`, if it exists.
     fn sqrt_in_place(&mut self) -> Option<&mut Self> {
         (*self).sqrt().map(|sqrt| {
@@ -227,6 +405,33 @@ pub trait Field:
         })
     }
 
+    /// Returns a cube root of self, if it exists.
+    ///
+    /// If the field has more than one cube root of `self`, which one is
+    /// returned is unspecified.
+    #[must_use]
+    fn cube_root(&self) -> Option<Self> {
+        generic_cube_root(self)
+    }
+
+    /// Returns `self^(num / den)`, i.e. a `den`-th root of `self^num`, if it
+    /// exists. Only `den` in `{1, 2, 3}` is supported, using [`Field::sqrt`]
+    /// and [`Field::cube_root`] respectively.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is not `1`, `2` or `3`.
+    #[must_use]
+    fn pow_rational(&self, num: u64, den: u64) -> Option<Self> {
+        let power = self.pow([num]);
+        match den {
+            1 => Some(power),
+            2 => power.sqrt(),
+            3 => power.cube_root(),
+            _ => panic!("only denominators 1, 2 and 3 are supported"),
+        }
+    }
+
     /// Returns `self * self`.
     #[must_use]
     fn square(&self) -> Self;
@@ -234,10 +439,41 @@ pub trait Field:
     /// Squares `self` in place.
     fn square_in_place(&mut self) -> &mut Self;
 
+    /// Returns `self^(2^k)`, computed by `k` consecutive squarings.
+    #[must_use]
+    fn pow2k(&self, k: usize) -> Self {
+        let mut result = *self;
+        result.pow2k_in_place(k);
+        result
+    }
+
+    /// Sets `self` to `self^(2^k)`, computed by `k` consecutive squarings.
+    fn pow2k_in_place(&mut self, k: usize) -> &mut Self {
+        for _ in 0..k {
+            self.square_in_place();
+        }
+        self
+    }
+
     /// Computes the multiplicative inverse of `self` if `self` is nonzero.
     #[must_use]
     fn inverse(&self) -> Option<Self>;
 
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// zeroizing the temporaries of the computation before returning.
+    ///
+    /// This trades a little speed for not leaving values derived from `self`
+    /// in memory, which matters when `self` is secret. The scrubbing is best
+    /// effort: it covers the named temporaries, but not copies the compiler
+    /// may make in registers or spill slots.
+    ///
+    /// The default falls back to [`Self::inverse`] and scrubs nothing; the
+    /// fields in this crate override it.
+    #[must_use]
+    fn inverse_zeroizing(&self) -> Option<Self> {
+        self.inverse()
+    }
+
     /// If `self.inverse().is_none()`, this just returns `None`. Otherwise, it sets
     /// `self` to `self.inverse().unwrap()`.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
@@ -252,14 +488,47 @@ pub trait Field:
         sum
     }
 
+    /// Returns `sum([a_i * b_i])`, consuming `a` and `b` in lockstep.
+    ///
+    /// Iteration stops as soon as either iterator is exhausted, so the extra
+    /// elements of the longer one are ignored.
+    #[inline]
+    fn sum_of_products_iter<I, J>(a: I, b: J) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+        J: IntoIterator<Item = Self>,
+    {
+        a.into_iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
     /// Exponentiates this element by a power of the base prime modulus via
     /// the Frobenius automorphism.
+    ///
+    /// The Frobenius automorphism has order [`Self::extension_degree`], so
+    /// `power` is reduced modulo the extension degree; in particular,
+    /// `frobenius_map(Self::extension_degree())` is the identity. For prime
+    /// fields this is a no-op.
     fn frobenius_map(&mut self, power: usize);
 
+    /// Applies [`Self::frobenius_map`] to `self` in place, returning `self`
+    /// to allow chaining.
+    fn frobenius_map_in_place(&mut self, power: usize) -> &mut Self {
+        self.frobenius_map(power);
+        self
+    }
+
     /// Exponentiates this element by a number represented with `u64` limbs,
     /// least significant limb first.
+    ///
+    /// An empty `exp` represents zero, and any element to the power of zero,
+    /// including zero itself, is one.
     #[must_use]
     fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        match significant_bits(exp.as_ref()) {
+            0 => return Self::one(),
+            1 => return *self,
+            _ => {},
+        }
         let mut res = Self::one();
 
         for i in BitIteratorBE::without_leading_zeros(exp) {
@@ -272,6 +541,33 @@ pub trait Field:
         res
     }
 
+    /// Like [`Self::pow`], but splits the limbs of `exp` into one window per
+    /// available rayon thread and exponentiates the windows on separate
+    /// tasks.
+    ///
+    /// Writing `exp = sum_i e_i * 2^(i * w)` for windows `e_i` of `w` bits,
+    /// this computes the bases `self^(2^(i * w))` by repeated squaring, raises
+    /// each base to its window `e_i` on its own task, so that each task only
+    /// squares over its own window, and multiplies the partial powers.
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    fn pow_parallel<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let windows = pow_parallel_windows(exp.as_ref(), rayon::current_num_threads());
+        let window_bits = windows.first().map_or(0, |w| 64 * w.len());
+        let mut base = *self;
+        let mut tasks = Vec::with_capacity(windows.len());
+        for (i, window) in windows.into_iter().enumerate() {
+            if i > 0 {
+                base.pow2k_in_place(window_bits);
+            }
+            tasks.push((base, window));
+        }
+        tasks
+            .into_par_iter()
+            .map(|(base, window)| base.pow(window))
+            .reduce(Self::one, |a, b| a * b)
+    }
+
     /// Exponentiates a field element `f` by a number represented with `u64`
     /// limbs, using a precomputed table containing as many powers of 2 of
     /// `f` as the 1 + the floor of log2 of the exponent `exp`, starting
@@ -289,6 +585,43 @@ pub trait Field:
         }
         Some(res)
     }
+
+    /// Returns `prod_i bases[i]^exps[i]`, where each exponent is given by its
+    /// `u64` limbs, least significant first.
+    ///
+    /// This uses simultaneous (Straus) exponentiation: the bits of all
+    /// exponents are processed together from the most significant one down,
+    /// so a single squaring per bit position is shared by all bases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bases` and `exps` have different lengths.
+    fn product_of_powers<S: AsRef<[u64]>>(bases: &[Self], exps: &[S]) -> Self {
+        assert_eq!(
+            bases.len(),
+            exps.len(),
+            "`bases` and `exps` must have the same length"
+        );
+        let num_bits = exps
+            .iter()
+            .map(|exp| significant_bits(exp.as_ref()))
+            .max()
+            .unwrap_or(0);
+        let mut res = Self::one();
+        for i in (0..num_bits).rev() {
+            res.square_in_place();
+            for (base, exp) in bases.iter().zip(exps) {
+                let limbs = exp.as_ref();
+                if limbs
+                    .get(i / 64)
+                    .map_or(false, |limb| (limb >> (i % 64)) & 1 == 1)
+                {
+                    res *= base;
+                }
+            }
+        }
+        res
+    }
 }
 
 /// Fields that have a cyclotomic multiplicative subgroup, and which can
@@ -329,6 +662,23 @@ pub trait CyclotomicMultSubgroup: Field {
         self.square_in_place()
     }
 
+    /// Compute `self^(2^count)` by `count` consecutive squarings in the
+    /// cyclotomic subgroup. By default this repeatedly invokes
+    /// [`Self::cyclotomic_square_in_place`], but for degree 12 extensions the
+    /// squarings can be carried out in a compressed representation, which is
+    /// decompressed only once at the end.
+    ///
+    /// # Warning
+    ///
+    /// This method should be invoked only when `self` is in the cyclotomic subgroup.
+    fn cyclotomic_square_batch(&self, count: usize) -> Self {
+        let mut result = *self;
+        for _ in 0..count {
+            result.cyclotomic_square_in_place();
+        }
+        result
+    }
+
     /// Compute the inverse of `self`. See [`Self::INVERSE_IS_FAST`] for details.
     /// Returns [`None`] if `self.is_zero()`, and [`Some`] otherwise.
     ///
@@ -385,6 +735,89 @@ pub trait CyclotomicMultSubgroup: Field {
     }
 }
 
+/// Computes a cube root of `x` in a field of order `q`, if it exists.
+///
+/// Write `q - 1 = 3^s * t` with `3 ∤ t`, and let `u = 3^{-1} mod t`. Then
+/// `y = x^u` satisfies `y^3 = x * w`, where `w` lies in the subgroup of order
+/// `3^s`. If `x` is a cube, so is `w`, and its cube root is found by computing
+/// the discrete logarithm of `w` with respect to a generator of that subgroup,
+/// one base-3 digit at a time.
+fn generic_cube_root<F: Field>(x: &F) -> Option<F> {
+    if x.is_zero() {
+        return Some(F::zero());
+    }
+    let characteristic = F::characteristic_biguint();
+    let q = characteristic.pow(F::extension_degree() as u32);
+    let three = BigUint::from(3u8);
+
+    if (&characteristic % &three).is_zero() {
+        // Cubing is the Frobenius automorphism, whose inverse is `x -> x^(q / 3)`.
+        return Some(x.pow((q / &three).to_u64_digits()));
+    }
+
+    let q_minus_one = &q - 1u8;
+    let mut s = 0u32;
+    let mut t = q_minus_one.clone();
+    while (&t % &three).is_zero() {
+        t /= &three;
+        s += 1;
+    }
+    // `3^{-1} mod t`: `t ≡ ±1 (mod 3)`, so one of `(t + 1) / 3` and `(2t + 1) / 3` is integral.
+    let u = if (&t % &three) == BigUint::from(2u8) {
+        (&t + 1u8) / &three
+    } else {
+        ((&t << 1u32) + 1u8) / &three
+    };
+    let mut y = x.pow(u.to_u64_digits());
+    if s == 0 {
+        return Some(y);
+    }
+
+    // `w = y^3 / x` lies in the subgroup of order `3^s`.
+    let w = y.square() * y * x.inverse().unwrap();
+    // Find a cubic non-residue `c`, so that `g = c^t` generates the subgroup of
+    // order `3^s`. Candidates have all coefficients nonzero, as elements of
+    // proper subfields may all be cubes.
+    let cubic_residue_exp = (&q_minus_one / &three).to_u64_digits();
+    let g = (2u64..)
+        .map(|i| {
+            let mut elems = ark_std::vec![F::BasePrimeField::one(); F::extension_degree() as usize];
+            elems[0] = F::BasePrimeField::from(i);
+            F::from_base_prime_field_elems(&elems).unwrap()
+        })
+        .find(|c| !c.pow(&cubic_residue_exp).is_one())
+        .unwrap()
+        .pow(t.to_u64_digits());
+    let g_inv = g.inverse().unwrap();
+    // A primitive cube root of unity.
+    let zeta = g.pow(three.pow(s - 1).to_u64_digits());
+
+    // Compute the discrete logarithm `e` of `w` to the base `g`, one base-3 digit at a time.
+    let mut e = BigUint::zero();
+    let mut w_times_g_inv_e = w;
+    for i in 0..s {
+        let h = w_times_g_inv_e.pow(three.pow(s - 1 - i).to_u64_digits());
+        let digit = if h.is_one() {
+            0u8
+        } else if h == zeta {
+            1
+        } else {
+            2
+        };
+        if digit != 0 {
+            let step = three.pow(i) * digit;
+            w_times_g_inv_e *= g_inv.pow(step.to_u64_digits());
+            e += step;
+        }
+    }
+    // `w` is a cube if and only if `e` is divisible by 3.
+    if !(&e % &three).is_zero() {
+        return None;
+    }
+    y *= g_inv.pow((e / &three).to_u64_digits());
+    Some(y)
+}
+
 /// Helper function to calculate the double-and-add loop for exponentiation.
 fn exp_loop<F: CyclotomicMultSubgroup, I: Iterator<Item = i8>>(f: &mut F, e: I) {
     // If the inverse is fast and we're using naf, we compute the inverse of the base.
@@ -415,6 +848,43 @@ fn exp_loop<F: CyclotomicMultSubgroup, I: Iterator<Item = i8>>(f: &mut F, e: I)
     *f = res;
 }
 
+/// The reason why [`FftField::try_get_root_of_unity`] found no root of unity
+/// of the requested order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RootOfUnityError {
+    /// The order is of the supported form, but exceeds the size of the
+    /// largest subgroup of that form.
+    OrderTooLarge,
+    /// The order is zero, or has a prime factor other than two and
+    /// [`FftField::SMALL_SUBGROUP_BASE`].
+    NotSmooth,
+    /// The order is not a power of two, and the field does not define the
+    /// small-subgroup parameters needed for mixed-radix orders.
+    MissingSubgroupParams,
+}
+
+impl ark_std::error::Error for RootOfUnityError {}
+
+impl fmt::Display for RootOfUnityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootOfUnityError::OrderTooLarge => {
+                write!(
+                    f,
+                    "the order exceeds the size of the largest supported subgroup"
+                )
+            },
+            RootOfUnityError::NotSmooth => {
+                write!(f, "the order is not a product of supported prime powers")
+            },
+            RootOfUnityError::MissingSubgroupParams => write!(
+                f,
+                "the order is not a power of two and no small subgroup is defined"
+            ),
+        }
+    }
+}
+
 /// The interface for fields that are able to be used in FFTs.
 pub trait FftField: Field {
     /// The generator of the multiplicative group of the field
@@ -449,26 +919,38 @@ pub trait FftField: Field {
     /// `FftConfig::LARGE_SUBGROUP_ROOT_OF_UNITY`
     /// (for n = 2^i * FftConfig::SMALL_SUBGROUP_BASE^j for some i, j).
     fn get_root_of_unity(n: u64) -> Option<Self> {
+        Self::try_get_root_of_unity(n).ok()
+    }
+
+    /// Like [`Self::get_root_of_unity`], but reports why no root of unity of
+    /// order `n` is available.
+    fn try_get_root_of_unity(n: u64) -> Result<Self, RootOfUnityError> {
+        if n == 0 {
+            return Err(RootOfUnityError::NotSmooth);
+        }
         let mut omega: Self;
         if let Some(large_subgroup_root_of_unity) = Self::LARGE_SUBGROUP_ROOT_OF_UNITY {
-            let q = Self::SMALL_SUBGROUP_BASE.expect(
-                "LARGE_SUBGROUP_ROOT_OF_UNITY should only be set in conjunction with SMALL_SUBGROUP_BASE",
-            ) as u64;
-            let small_subgroup_base_adicity = Self::SMALL_SUBGROUP_BASE_ADICITY.expect(
-                "LARGE_SUBGROUP_ROOT_OF_UNITY should only be set in conjunction with SMALL_SUBGROUP_BASE_ADICITY",
-            );
+            let (q, small_subgroup_base_adicity) =
+                match (Self::SMALL_SUBGROUP_BASE, Self::SMALL_SUBGROUP_BASE_ADICITY) {
+                    (Some(q), Some(adicity)) => (q as u64, adicity),
+                    _ => return Err(RootOfUnityError::MissingSubgroupParams),
+                };
 
             let q_adicity = k_adicity(q, n);
-            let q_part = q.checked_pow(q_adicity)?;
+            let q_part = q
+                .checked_pow(q_adicity)
+                .ok_or(RootOfUnityError::OrderTooLarge)?;
 
             let two_adicity = k_adicity(2, n);
-            let two_part = 2u64.checked_pow(two_adicity)?;
+            let two_part = 2u64
+                .checked_pow(two_adicity)
+                .ok_or(RootOfUnityError::OrderTooLarge)?;
 
-            if n != two_part * q_part
-                || (two_adicity > Self::TWO_ADICITY)
-                || (q_adicity > small_subgroup_base_adicity)
-            {
-                return None;
+            if n != two_part * q_part {
+                return Err(RootOfUnityError::NotSmooth);
+            }
+            if two_adicity > Self::TWO_ADICITY || q_adicity > small_subgroup_base_adicity {
+                return Err(RootOfUnityError::OrderTooLarge);
             }
 
             omega = large_subgroup_root_of_unity;
@@ -476,27 +958,150 @@ pub trait FftField: Field {
                 omega = omega.pow(&[q as u64]);
             }
 
-            for _ in two_adicity..Self::TWO_ADICITY {
-                omega.square_in_place();
-            }
+            omega.pow2k_in_place((Self::TWO_ADICITY - two_adicity) as usize);
         } else {
-            // Compute the next power of 2.
-            let size = n.next_power_of_two() as u64;
-            let log_size_of_group = ark_std::log2(usize::try_from(size).expect("too large"));
-
-            if n != size || log_size_of_group > Self::TWO_ADICITY {
-                return None;
+            if !n.is_power_of_two() {
+                return Err(RootOfUnityError::MissingSubgroupParams);
+            }
+            let log_size_of_group = n.trailing_zeros();
+            if log_size_of_group > Self::TWO_ADICITY {
+                return Err(RootOfUnityError::OrderTooLarge);
             }
 
             // Compute the generator for the multiplicative subgroup.
             // It should be 2^(log_size_of_group) root of unity.
             omega = Self::TWO_ADIC_ROOT_OF_UNITY;
-            for _ in log_size_of_group..Self::TWO_ADICITY {
-                omega.square_in_place();
-            }
+            omega.pow2k_in_place((Self::TWO_ADICITY - log_size_of_group) as usize);
+        }
+        Ok(omega)
+    }
+
+    /// Returns a generator of the multiplicative subgroup of order
+    /// `SMALL_SUBGROUP_BASE^k`, which has no two-adic factor.
+    ///
+    /// Returns `None` if no small subgroup is configured, or if `k` exceeds
+    /// [`Self::SMALL_SUBGROUP_BASE_ADICITY`].
+    fn get_small_subgroup_root_of_unity(k: u32) -> Option<Self> {
+        let q = Self::SMALL_SUBGROUP_BASE? as u64;
+        let adicity = Self::SMALL_SUBGROUP_BASE_ADICITY?;
+        let mut omega = Self::LARGE_SUBGROUP_ROOT_OF_UNITY?;
+        if k > adicity {
+            return None;
+        }
+        omega.pow2k_in_place(Self::TWO_ADICITY as usize);
+        for _ in k..adicity {
+            omega = omega.pow([q]);
         }
         Some(omega)
     }
+
+    /// Returns the inverse of the root of unity of order `n` given by
+    /// [`Self::get_root_of_unity`], if one exists.
+    ///
+    /// Since `omega^n = 1`, the inverse is `omega^(n - 1)`, so no general
+    /// inversion is performed.
+    fn get_inverse_root_of_unity(n: u64) -> Option<Self> {
+        Self::get_root_of_unity(n).map(|omega| omega.pow([n - 1]))
+    }
+
+    /// Returns an element `g` such that the coset `g * H` is disjoint from
+    /// every proper multiplicative subgroup `H`, for use as the offset of
+    /// coset evaluation domains.
+    ///
+    /// This is [`Self::GENERATOR`], which generates the whole multiplicative
+    /// group and hence lies in no proper subgroup.
+    fn coset_generator() -> Self {
+        Self::GENERATOR
+    }
+
+    /// Returns `(omega, g)`, where `omega` is the root of unity of order `n`
+    /// given by [`Self::get_root_of_unity`], and `g` is
+    /// [`Self::coset_generator`], so that `g * <omega>` is a coset of the
+    /// order-`n` subgroup that is distinct from the subgroup itself.
+    ///
+    /// Returns `None` if there is no root of unity of order `n`.
+    fn get_coset(n: u64) -> Option<(Self, Self)> {
+        let omega = Self::get_root_of_unity(n)?;
+        let g = Self::coset_generator();
+        debug_assert!(
+            !g.pow([n]).is_one(),
+            "the coset generator lies in the order-n subgroup"
+        );
+        Some((omega, g))
+    }
+
+    /// Returns the inverse of [`Self::GENERATOR`].
+    ///
+    /// Prime fields backed by [`MontConfig`](crate::MontConfig) return a
+    /// constant computed at compile time; the default inverts the generator.
+    #[inline]
+    fn generator_inv() -> Self {
+        Self::GENERATOR.inverse().unwrap()
+    }
+
+    /// Returns `self * GENERATOR^(-i)`.
+    ///
+    /// This uses [`Self::generator_inv`], so prime fields backed by
+    /// [`MontConfig`](crate::MontConfig) perform no inversion.
+    #[must_use]
+    fn mul_by_generator_inv_pow(&self, i: u64) -> Self {
+        *self * Self::generator_inv().pow([i])
+    }
+
+    /// Returns the inverse of `self`, assuming that `self` is a
+    /// `2^log_n`-th root of unity.
+    ///
+    /// Since `self^(2^log_n) = 1`, the inverse is `self^(2^log_n - 1)`,
+    /// which is computed with `log_n - 1` squarings and multiplications
+    /// instead of a general inversion. The result is meaningless if `self`
+    /// is not a `2^log_n`-th root of unity.
+    #[must_use]
+    fn inverse_root_of_unity(&self, log_n: u32) -> Self {
+        if log_n == 0 {
+            return Self::one();
+        }
+        // Invariant: `result = self^(2^i - 1)` after the `i`-th iteration.
+        let mut result = *self;
+        for _ in 1..log_n {
+            result.square_in_place();
+            result *= self;
+        }
+        result
+    }
+
+    /// Returns the powers `omega^0, ..., omega^(2^log_n - 1)` of the root of
+    /// unity `omega = Self::get_root_of_unity(2^log_n)` in bit-reversed order,
+    /// i.e. the `i`-th entry is `omega^rev(i)`, where `rev` reverses the
+    /// lowest `log_n` bits. This is the layout that many in-place FFT
+    /// algorithms consume directly.
+    ///
+    /// Returns `None` if there is no root of unity of order `2^log_n`.
+    fn primitive_nth_root_powers_bit_reversed(log_n: u32) -> Option<Vec<Self>> {
+        let omega = Self::get_root_of_unity(1u64.checked_shl(log_n)?)?;
+        // `roots[k]` is a root of unity of order `2^k`.
+        let mut roots = Vec::with_capacity(log_n as usize + 1);
+        roots.push(omega);
+        for _ in 0..log_n {
+            roots.push(roots.last().unwrap().square());
+        }
+        roots.reverse();
+
+        // If `powers` holds the powers of `roots[k - 1] = roots[k]^2` in
+        // bit-reversed order, then those of `roots[k]` are `powers` followed
+        // by `powers` multiplied by `roots[k]`: for `i < 2^(k - 1)`, the
+        // `k`-bit reversals of `i` and `i + 2^(k - 1)` are `2 * rev(i)` and
+        // `2 * rev(i) + 1`, where `rev` is the `(k - 1)`-bit reversal.
+        let mut powers = Vec::with_capacity(1 << log_n);
+        powers.push(Self::one());
+        for root in &roots[1..] {
+            let len = powers.len();
+            for i in 0..len {
+                let power = powers[i] * root;
+                powers.push(power);
+            }
+        }
+        Some(powers)
+    }
 }
 
 /// The interface for a prime field, i.e. the field of integers modulo a prime $p$.  
@@ -535,6 +1140,16 @@ pub trait PrimeField:
     /// The modulus `p`.
     const MODULUS: Self::BigInt;
 
+    /// The value `p - 1`.
+    ///
+    /// ```
+    /// use ark_ff::{Field, PrimeField};
+    /// use ark_test_curves::bls12_381::Fr;
+    ///
+    /// assert_eq!(Fr::NEG_ONE.into_bigint(), Fr::MODULUS_MINUS_ONE);
+    /// ```
+    const MODULUS_MINUS_ONE: Self::BigInt;
+
     /// The value `(p - 1)/ 2`.
     const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt;
 
@@ -569,14 +1184,22 @@ pub trait PrimeField:
         // Guaranteed to not be None, as the input is less than the modulus size.
         let mut res = Self::from_random_bytes(&bytes_to_directly_convert).unwrap();
 
-        // Update the result, byte by byte.
+        // Update the result, one u64 limb at a time.
         // We go through existing field arithmetic, which handles the reduction.
-        // TODO: If we need higher speeds, parse more bytes at once, or implement
-        // modular multiplication by a u64
-        let window_size = Self::from(256u64);
-        for byte in bytes[num_bytes_to_directly_convert..].iter() {
+        // If the number of remaining bytes is not a multiple of 8, the leading
+        // bytes are consumed first as a shorter limb.
+        let limb_from_be_bytes =
+            |limb: &[u8]| limb.iter().fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        let remaining = &bytes[num_bytes_to_directly_convert..];
+        let (head, tail) = remaining.split_at(remaining.len() % 8);
+        if !head.is_empty() {
+            res *= Self::from(1u128 << (8 * head.len()));
+            res += Self::from(limb_from_be_bytes(head));
+        }
+        let window_size = Self::from(1u128 << 64);
+        for limb in tail.chunks_exact(8) {
             res *= window_size;
-            res += Self::from(*byte);
+            res += Self::from(limb_from_be_bytes(limb));
         }
         res
     }
@@ -589,74 +1212,855 @@ pub trait PrimeField:
         bytes_copy.reverse();
         Self::from_be_bytes_mod_order(&bytes_copy)
     }
-}
 
-/// Indication of the field element's quadratic residuosity
-///
-/// # Examples
-/// ```
-/// # use ark_std::test_rng;
-/// # use ark_std::UniformRand;
-/// # use ark_test_curves::{LegendreSymbol, Field, bls12_381::Fq as Fp};
-/// let a: Fp = Fp::rand(&mut test_rng());
-/// let b = a.square();
-/// assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
-/// ```
-#[derive(Debug, PartialEq, Eq)]
-pub enum LegendreSymbol {
-    Zero = 0,
-    QuadraticResidue = 1,
-    QuadraticNonResidue = -1,
-}
+    /// Returns the little-endian bit decomposition of `self`, consisting of
+    /// exactly `Self::MODULUS_BIT_SIZE` bits.
+    fn to_bits_le(&self) -> Vec<bool> {
+        BitIteratorLE::new(self.into_bigint())
+            .take(Self::MODULUS_BIT_SIZE as usize)
+            .collect()
+    }
 
-impl LegendreSymbol {
-    /// Returns true if `self.is_zero()`.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ark_std::test_rng;
-    /// # use ark_std::UniformRand;
-    /// # use ark_test_curves::{LegendreSymbol, Field, bls12_381::Fq as Fp};
-    /// let a: Fp = Fp::rand(&mut test_rng());
-    /// let b: Fp = a.square();
-    /// assert!(!b.legendre().is_zero());
-    /// ```
-    pub fn is_zero(&self) -> bool {
-        *self == LegendreSymbol::Zero
+    /// Returns the big-endian bit decomposition of `self`, consisting of
+    /// exactly `Self::MODULUS_BIT_SIZE` bits.
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits = self.to_bits_le();
+        bits.reverse();
+        bits
     }
 
-    /// Returns true if `self` is a quadratic non-residue.
+    /// Reads bits in little-endian, and converts them to a field element.
+    /// If the integer represented by `bits` is larger than the modulus `p`,
+    /// this method performs the appropriate reduction.
+    fn from_bits_le(bits: &[bool]) -> Self {
+        let bytes = bits
+            .chunks(8)
+            .map(|byte| {
+                byte.iter()
+                    .enumerate()
+                    .fold(0u8, |acc, (i, bit)| acc | (u8::from(*bit) << i))
+            })
+            .collect::<Vec<_>>();
+        Self::from_le_bytes_mod_order(&bytes)
+    }
+
+    /// Reads bits in big-endian, and converts them to a field element.
+    /// If the integer represented by `bits` is larger than the modulus `p`,
+    /// this method performs the appropriate reduction.
+    fn from_bits_be(bits: &[bool]) -> Self {
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        Self::from_bits_le(&bits)
+    }
+
+    /// Converts a value in Montgomery form with respect to a foreign radix
+    /// `R' = 2^foreign_r_bits` into a field element. That is, given
+    /// `value = x * R' mod p` in little-endian `u64` limbs, this returns `x`.
     ///
-    /// # Examples
-    /// ```
-    /// # use ark_test_curves::{Fp2Config, Field, LegendreSymbol, bls12_381::{Fq, Fq2Config}};
-    /// let a: Fq = Fq2Config::NONRESIDUE;
-    /// assert!(a.legendre().is_qnr());
-    /// ```
-    pub fn is_qnr(&self) -> bool {
-        *self == LegendreSymbol::QuadraticNonResidue
+    /// This is useful when interoperating with libraries whose Montgomery
+    /// radix differs from ours, e.g. because they use a different number of
+    /// limbs. The input does not need to be reduced modulo `p`.
+    fn from_foreign_montgomery(value: &[u64], foreign_r_bits: u32) -> Self {
+        let bytes = value
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<_>>();
+        let value = Self::from_le_bytes_mod_order(&bytes);
+        // `R'^{-1}`, which exists since `p` is odd.
+        let foreign_r_inv = Self::from(2u64)
+            .pow([u64::from(foreign_r_bits)])
+            .inverse()
+            .unwrap();
+        value * foreign_r_inv
     }
 
-    /// Returns true if `self` is a quadratic residue.
-    /// # Examples
-    /// ```
-    /// # use ark_std::test_rng;
-    /// # use ark_test_curves::bls12_381::Fq as Fp;
-    /// # use ark_std::UniformRand;
-    /// # use ark_ff::{LegendreSymbol, Field};
-    /// let a: Fp = Fp::rand(&mut test_rng());
-    /// let b: Fp = a.square();
-    /// assert!(b.legendre().is_qr());
-    /// ```
-    pub fn is_qr(&self) -> bool {
-        *self == LegendreSymbol::QuadraticResidue
+    /// Encodes the fixed-point number `value / 2^frac_bits` as a field
+    /// element, i.e. returns `value * 2^{-frac_bits}`. Negative values are
+    /// mapped to the negation of the encoding of their absolute value.
+    fn from_fixed_point(value: i64, frac_bits: u32) -> Self {
+        let magnitude = Self::from(value.unsigned_abs());
+        // `2^{-1}`, which exists since `p` is odd.
+        let two_inv = Self::from(2u64).inverse().unwrap();
+        let result = magnitude * two_inv.pow([u64::from(frac_bits)]);
+        if value < 0 {
+            -result
+        } else {
+            result
+        }
     }
-}
 
-/// Precomputation that makes computing square roots faster
-/// A particular variant should only be instantiated if the modulus satisfies
-/// the corresponding condition.
-#[non_exhaustive]
+    /// Approximates `value` as a fixed-point number with `frac_bits`
+    /// fractional bits: `value * 2^frac_bits` is rounded to the nearest
+    /// integer, with ties rounded away from zero, and encoded with
+    /// [`Self::from_fixed_point`].
+    ///
+    /// Returns `None` if `value` is NaN or infinite, or if the rounded integer
+    /// does not fit in an `i64`.
+    fn from_f64(value: f64, frac_bits: u32) -> Option<Self> {
+        // `2^63`, the first magnitude that does not fit in an `i64`.
+        const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Self::zero());
+        }
+        // Doubling is exact, and stops after at most about 1100 iterations
+        // since even the smallest subnormal `value` then exceeds the limit.
+        let mut scaled = value;
+        for _ in 0..frac_bits {
+            if !(-LIMIT..LIMIT).contains(&scaled) {
+                return None;
+            }
+            scaled *= 2.0;
+        }
+        if !(-LIMIT..LIMIT).contains(&scaled) {
+            return None;
+        }
+        // The cast truncates towards zero, and is exact in this range.
+        let truncated = scaled as i64;
+        let fraction = scaled - truncated as f64;
+        let rounded = if fraction >= 0.5 {
+            truncated.checked_add(1)?
+        } else if fraction <= -0.5 {
+            truncated.checked_sub(1)?
+        } else {
+            truncated
+        };
+        Some(Self::from_fixed_point(rounded, frac_bits))
+    }
+
+    /// Returns the integer difference `self - prev` between the canonical
+    /// representatives of `self` and `prev`, e.g. for delta-encoding a sorted
+    /// sequence of field elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self < prev`.
+    fn canonical_delta(&self, prev: &Self) -> Self::BigInt {
+        let mut delta = self.into_bigint();
+        let borrow = delta.sub_with_borrow(&prev.into_bigint());
+        assert!(!borrow, "`self` must not be smaller than `prev`");
+        delta
+    }
+
+    /// Reconstructs the field element whose canonical representative is
+    /// `prev + delta`, inverting [`PrimeField::canonical_delta`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prev + delta` is not smaller than the modulus.
+    fn from_canonical_delta(prev: &Self, delta: &Self::BigInt) -> Self {
+        let mut value = prev.into_bigint();
+        let carry = value.add_with_carry(delta);
+        assert!(!carry, "`prev + delta` must be smaller than the modulus");
+        Self::from_bigint(value).expect("`prev + delta` must be smaller than the modulus")
+    }
+
+    /// Returns the Hilbert symbol `(a, b)` over this field, which is always
+    /// `1`: over a finite field, `z^2 = a * x^2 + b * y^2` has a non-trivial
+    /// solution for every `a` and `b`. This is a constant, provided for
+    /// generic code written against local fields.
+    fn hilbert_symbol(_a: &Self, _b: &Self) -> i8 {
+        1
+    }
+
+    /// Returns the sum of the base-`base` digits of the canonical
+    /// representative of `self`, e.g. as a checksum for hand-entered
+    /// constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is not in the range `2..=u32::MAX`.
+    fn digit_sum(&self, base: u64) -> u64 {
+        assert!(
+            (2..=u64::from(u32::MAX)).contains(&base),
+            "base must be in the range 2..=u32::MAX"
+        );
+        let mut value = self.into_bigint();
+        let mut sum = 0;
+        while !value.is_zero() {
+            // Divide `value` by `base`, from the most significant limb down.
+            let mut rem = 0u64;
+            for limb in value.as_mut().iter_mut().rev() {
+                let tmp = (u128::from(rem) << 64) | u128::from(*limb);
+                *limb = (tmp / u128::from(base)) as u64;
+                rem = (tmp % u128::from(base)) as u64;
+            }
+            sum += rem;
+        }
+        sum
+    }
+
+    /// Returns the first `max_terms` partial quotients of the continued
+    /// fraction expansion of the rational `x / p`, where `x` is the canonical
+    /// representative of `self`, or all of them if there are fewer.
+    ///
+    /// These are the quotients of the Euclidean algorithm on `(x, p)`, so the
+    /// first one is always zero. Partial quotients can be as large as `p`,
+    /// e.g. for `x = 1`, so they are returned as [`Self::BigInt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::fields::{Fp64, MontBackend, MontConfig, PrimeField};
+    ///
+    /// #[derive(MontConfig)]
+    /// #[modulus = "101"]
+    /// #[generator = "2"]
+    /// pub struct F101Config;
+    /// pub type F101 = Fp64<MontBackend<F101Config, 1>>;
+    ///
+    /// // 17 / 101 = 0 + 1 / (5 + 1 / (1 + 1 / 16))
+    /// let expansion = F101::from(17u64).continued_fraction(10);
+    /// assert_eq!(expansion, [0u64, 5, 1, 16].map(Into::into));
+    /// let truncated = F101::from(17u64).continued_fraction(2);
+    /// assert_eq!(truncated, [0u64, 5].map(Into::into));
+    /// ```
+    fn continued_fraction(&self, max_terms: usize) -> Vec<Self::BigInt> {
+        let mut quotients = Vec::new();
+        let (mut a, mut b) = (self.into_bigint(), Self::MODULUS);
+        while !b.is_zero() && quotients.len() < max_terms {
+            let (q, r) = a.div_rem(&b);
+            quotients.push(q);
+            a = b;
+            b = r;
+        }
+        quotients
+    }
+
+    /// Returns the first `precision` digits of the `p`-adic expansion of a
+    /// square root of the integer `x`, where `x` is the canonical
+    /// representative of `self`, or `None` if `self` is not a square.
+    ///
+    /// The root is lifted from [`Field::sqrt`] by Newton iteration modulo
+    /// `p^(2^i)`, so the first digit is `self.sqrt()`. The digits lie in
+    /// `0..p` and are therefore returned as [`Self::BigInt`].
+    fn hensel_lift_sqrt(&self, precision: u32) -> Option<Vec<Self::BigInt>> {
+        let root = self.sqrt()?;
+        let p: BigUint = Self::MODULUS.into();
+        let x: BigUint = self.into_bigint().into();
+        let mut root: BigUint = root.into_bigint().into();
+        // For `x = 0`, the root `0` is exact and needs no lifting. Otherwise,
+        // `2 * root` is invertible modulo every power of `p`.
+        if !self.is_zero() && precision > 1 {
+            let target = p.pow(precision);
+            // Invariant: `root^2 = x mod modulus`.
+            let mut modulus = p.clone();
+            while modulus < target {
+                modulus = (&modulus * &modulus).min(target.clone());
+                // `(2 * root)^-1 = (2 * root)^(phi(modulus) - 1)`, where
+                // `phi(p^k) = p^(k - 1) * (p - 1)`.
+                let phi = &modulus / &p * (&p - 1u8);
+                let inv = (&root * 2u8).modpow(&(phi - 1u8), &modulus);
+                let error = (&root * &root + &modulus - &x) % &modulus;
+                root = (&root + &modulus - error * inv % &modulus) % &modulus;
+            }
+        }
+        let mut digits = Vec::with_capacity(precision as usize);
+        for _ in 0..precision {
+            digits.push(Self::BigInt::try_from(&root % &p).unwrap());
+            root /= &p;
+        }
+        Some(digits)
+    }
+
+    /// Returns `self^(inner_base^inner_exp)`.
+    ///
+    /// For nonzero `self`, the exponent `inner_base^inner_exp` is reduced
+    /// modulo the order `p - 1` of the multiplicative group, so it is never
+    /// computed in full.
+    fn tower_pow(&self, inner_base: &Self::BigInt, inner_exp: &Self::BigInt) -> Self {
+        if self.is_zero() {
+            // The exponent is zero only if `inner_base = 0` and `inner_exp > 0`.
+            return if inner_base.is_zero() && !inner_exp.is_zero() {
+                Self::one()
+            } else {
+                Self::zero()
+            };
+        }
+        let order: BigUint = Self::MODULUS.into();
+        let order = order - 1u8;
+        let (inner_base, inner_exp): (BigUint, BigUint) =
+            ((*inner_base).into(), (*inner_exp).into());
+        let exp = inner_base.modpow(&inner_exp, &order);
+        self.pow(exp.to_u64_digits())
+    }
+
+    /// Returns the internal and canonical representations of `self`, for
+    /// logging and debugging.
+    ///
+    /// The default cannot see the internal representation, so it reports the
+    /// canonical limbs in both places; [`Fp`](crate::Fp) overrides it.
+    fn debug_repr(&self) -> FieldRepr {
+        let canonical = self.into_bigint();
+        FieldRepr::new(canonical.as_ref(), canonical)
+    }
+
+    /// Returns the canonical representative of `self` as a big-endian,
+    /// `0x`-prefixed, lower-case hexadecimal string without leading zeros,
+    /// e.g. `0x1f`.
+    fn to_hex_string(&self) -> String {
+        format!("0x{}", self.into_bigint().to_str_radix(16))
+    }
+
+    /// Parses a big-endian hexadecimal string, as returned by
+    /// [`Self::to_hex_string`]. The `0x` prefix is optional, both cases are
+    /// accepted, and leading zeros are allowed, but the value must be smaller
+    /// than the modulus.
+    fn from_hex_string(s: &str) -> Result<Self, HexParseError> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        if digits.is_empty() {
+            return Err(HexParseError::Empty);
+        }
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(HexParseError::InvalidDigit);
+        }
+        // The digits are valid, so this only fails if the value overflows.
+        Self::BigInt::from_str_radix(digits, 16)
+            .and_then(Self::from_bigint)
+            .ok_or(HexParseError::OutOfRange)
+    }
+
+    /// Returns the big-endian encoding of the canonical representative of
+    /// `self`, padded to the byte length of the modulus.
+    ///
+    /// Since all encodings have the same length, comparing them
+    /// lexicographically agrees with comparing the canonical representatives,
+    /// so they can be used as keys of byte-ordered maps.
+    fn sort_key_bytes(&self) -> Vec<u8> {
+        let num_bytes = ((Self::MODULUS_BIT_SIZE + 7) / 8) as usize;
+        let bytes = self.into_bigint().to_bytes_be();
+        bytes[bytes.len() - num_bytes..].to_vec()
+    }
+
+    /// Returns a short fingerprint of `self` for logging, made of the first
+    /// and last four hexadecimal digits of [`Self::sort_key_bytes`], e.g.
+    /// `a1b2…ef09`.
+    ///
+    /// If the modulus fits into four bytes, all digits are returned instead.
+    fn short_fingerprint(&self) -> String {
+        let hex = |bytes: &[u8]| {
+            bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        };
+        let bytes = self.sort_key_bytes();
+        if bytes.len() <= 4 {
+            hex(&bytes)
+        } else {
+            format!("{}…{}", hex(&bytes[..2]), hex(&bytes[bytes.len() - 2..]))
+        }
+    }
+
+    /// Interprets the UTF-8 bytes of `s` as a little-endian integer and
+    /// returns it as a field element, for embedding short labels.
+    ///
+    /// Returns `None` if the integer is not smaller than the modulus, or if
+    /// `s` ends with a NUL character, since trailing zero bytes would be lost.
+    /// Hence [`Self::try_into_str`] always recovers `s` from the result.
+    fn from_str_bytes(s: &str) -> Option<Self> {
+        if s.ends_with('\0') {
+            return None;
+        }
+        let value = BigUint::from_bytes_le(s.as_bytes());
+        Self::from_bigint(value.try_into().ok()?)
+    }
+
+    /// Returns the string encoded in `self` by [`Self::from_str_bytes`], or
+    /// `None` if the little-endian bytes of `self`, without trailing zeros,
+    /// are not valid UTF-8.
+    fn try_into_str(&self) -> Option<String> {
+        let mut bytes = self.into_bigint().to_bytes_le();
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        String::from_utf8(bytes).ok()
+    }
+
+    /// Returns the number of unused high bits in the last byte of the
+    /// canonical serialization, i.e. the largest [`Flags::BIT_SIZE`] for
+    /// which [`CanonicalSerializeWithFlags::serialize_with_flags`] produces
+    /// no more bytes than [`CanonicalSerialize::serialize_compressed`].
+    ///
+    /// Larger flags take an extra byte, so writing them to a buffer sized for
+    /// the flagless encoding fails.
+    ///
+    /// [`Flags::BIT_SIZE`]: ark_serialize::Flags::BIT_SIZE
+    fn flag_bit_capacity() -> usize {
+        (8 - Self::MODULUS_BIT_SIZE as usize % 8) % 8
+    }
+
+    /// Reduces `value` modulo the characteristic, also returning the quotient.
+    ///
+    /// The result `(r, q)` satisfies `value = q * MODULUS + r.into_bigint()`,
+    /// so `q` can serve as a witness that the reduction was done correctly,
+    /// e.g. in non-native field arithmetic gadgets.
+    fn reduce_with_quotient(value: &BigUint) -> (Self, BigUint) {
+        let modulus: BigUint = Self::MODULUS.into();
+        (Self::from(value % &modulus), value / &modulus)
+    }
+
+    /// Returns the balanced representative of `self`, i.e. the signed
+    /// integer in `(-MODULUS / 2, MODULUS / 2]` that is congruent to `self`.
+    fn to_balanced_bigint(&self) -> num_bigint::BigInt {
+        if self.into_bigint() > Self::MODULUS_MINUS_ONE_DIV_TWO {
+            let magnitude: BigUint = (-*self).into();
+            -num_bigint::BigInt::from(magnitude)
+        } else {
+            let value: BigUint = (*self).into();
+            value.into()
+        }
+    }
+
+    /// Reduces the signed integer `value` modulo the characteristic.
+    ///
+    /// This is the inverse of [`Self::to_balanced_bigint`].
+    fn from_bigint_signed(value: &num_bigint::BigInt) -> Self {
+        let modulus: BigUint = Self::MODULUS.into();
+        let reduced = Self::from(value.magnitude() % &modulus);
+        match value.sign() {
+            num_bigint::Sign::Minus => -reduced,
+            _ => reduced,
+        }
+    }
+
+    /// Recombines the little-endian digits `digits` in radix `2^radix_bits`,
+    /// returning `sum_i digits[i] * 2^(i * radix_bits)` reduced modulo the
+    /// characteristic.
+    ///
+    /// In debug builds, this panics if a digit is not smaller than
+    /// `2^radix_bits`.
+    fn from_base_field_digits(digits: &[Self], radix_bits: u32) -> Self {
+        debug_assert!(
+            digits
+                .iter()
+                .all(|d| d.into_bigint().num_bits() <= radix_bits),
+            "digits must be smaller than 2^{}",
+            radix_bits
+        );
+        let radix = Self::from(2u8).pow([u64::from(radix_bits)]);
+        digits
+            .iter()
+            .rev()
+            .fold(Self::zero(), |acc, digit| acc * radix + digit)
+    }
+
+    /// Returns whether `self` generates the multiplicative group of the
+    /// field, given the factorization of `p - 1` as pairs `(q, e)` of
+    /// distinct primes `q` and their multiplicities `e`.
+    ///
+    /// This checks that `self^((p - 1) / q) != 1` for every prime factor `q`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the factorization does not multiply to `p - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ark_ff::fields::{Fp64, MontBackend, MontConfig, PrimeField};
+    ///
+    /// #[derive(MontConfig)]
+    /// #[modulus = "17"]
+    /// #[generator = "3"]
+    /// pub struct F17Config;
+    /// pub type F17 = Fp64<MontBackend<F17Config, 1>>;
+    ///
+    /// // 17 - 1 = 2^4
+    /// let factorization = [(2, 4)];
+    /// assert!(F17::from(3u8).is_primitive_element(&factorization));
+    /// // 16 = -1 has order 2.
+    /// assert!(!F17::from(16u8).is_primitive_element(&factorization));
+    /// ```
+    fn is_primitive_element(&self, factorization_of_p_minus_1: &[(u64, u32)]) -> bool {
+        let p_minus_one: BigUint = Self::MODULUS_MINUS_ONE.into();
+        let product = factorization_of_p_minus_1
+            .iter()
+            .fold(BigUint::one(), |acc, &(q, e)| acc * BigUint::from(q).pow(e));
+        assert!(
+            product == p_minus_one,
+            "the factorization does not multiply to `p - 1`"
+        );
+        !self.is_zero()
+            && factorization_of_p_minus_1.iter().all(|&(q, _)| {
+                let exp = &p_minus_one / q;
+                !self.pow(exp.to_u64_digits()).is_one()
+            })
+    }
+
+    /// Returns whether `x^degree - self` is irreducible over the extension of
+    /// degree `base_degree` of this field, i.e. whether `self` can be used as
+    /// the non-residue of a binomial extension of degree `degree` of that
+    /// field.
+    ///
+    /// Writing `q = p^base_degree`, this holds iff for every prime `r`
+    /// dividing `degree`, `r` divides `q - 1` and `self` is not an `r`-th
+    /// power, and `q = 1 mod 4` if `4` divides `degree`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_degree` or `degree` is zero.
+    fn is_valid_extension_nonresidue(&self, base_degree: u32, degree: u32) -> bool {
+        assert!(
+            base_degree > 0 && degree > 0,
+            "extension degrees must be positive"
+        );
+        if degree == 1 {
+            return true;
+        }
+        if self.is_zero() {
+            return false;
+        }
+        let modulus: BigUint = Self::MODULUS.into();
+        let q_minus_one = modulus.pow(base_degree) - 1u8;
+        binomial_is_irreducible(&q_minus_one, degree, |exp| {
+            self.pow(exp.to_u64_digits()).is_one()
+        })
+    }
+
+    /// Finds non-residues for a tower of binomial extensions of this field,
+    /// whose `i`-th level has degree `degrees[i]` over the level below, such
+    /// as `[2, 3, 2]` for `Fp -> Fp2 -> Fp6 -> Fp12`.
+    ///
+    /// The non-residue of level `i` is an element of the field below it, of
+    /// degree `D = degrees[0] * ... * degrees[i - 1]` over this field, and is
+    /// returned as its `D` coordinates over this field. Coordinates are
+    /// ordered as by [`Field::to_base_prime_field_elements`] on the
+    /// corresponding [`QuadExtField`] and [`CubicExtField`] towers: the
+    /// coordinates of the constant coefficient come first.
+    ///
+    /// Each level uses the first of `-1, 1, -2, 2, ...` that passes
+    /// [`Self::is_valid_extension_nonresidue`]. If none does, as for a
+    /// quadratic extension above an even-degree level, it uses the first
+    /// valid element outside this field whose coordinates lie in
+    /// `{0, 1, -1, 2, -2}`, reading the coordinates as the base-5 digits of
+    /// `1, 2, 3, ...`, least significant first.
+    ///
+    /// Returns `None` if no non-residue is found among the first candidates
+    /// for some level.
+    fn build_tower_nonresidues(degrees: &[u32]) -> Option<Vec<Vec<Self>>> {
+        const SEARCH_BOUND: u64 = 256;
+        const EXTENSION_SEARCH_BOUND: u64 = 4096;
+        let modulus: BigUint = Self::MODULUS.into();
+        let mut levels: Vec<(u32, Vec<Self>)> = Vec::with_capacity(degrees.len());
+        let mut base_degree = 1u32;
+        for &degree in degrees {
+            let len = base_degree as usize;
+            let prime_nonresidue = (1..=SEARCH_BOUND)
+                .flat_map(|k| [-Self::from(k), Self::from(k)])
+                .find(|c| c.is_valid_extension_nonresidue(base_degree, degree));
+            let nonresidue = match prime_nonresidue {
+                Some(c) => {
+                    let mut element = ark_std::vec![Self::zero(); len];
+                    element[0] = c;
+                    element
+                },
+                None => {
+                    let q_minus_one = modulus.pow(base_degree) - 1u8;
+                    let mut one = ark_std::vec![Self::zero(); len];
+                    one[0] = Self::one();
+                    (1..=EXTENSION_SEARCH_BOUND)
+                        .filter_map(|mut k| {
+                            let mut element = ark_std::vec![Self::zero(); len];
+                            for c in &mut element {
+                                let digit = k % 5;
+                                k /= 5;
+                                let value = Self::from((digit + 1) / 2);
+                                *c = if digit % 2 == 0 { -value } else { value };
+                            }
+                            // Skip elements that do not fit into `len`
+                            // coordinates, or that lie in this field.
+                            (k == 0 && element[1..].iter().any(|c| !c.is_zero())).then(|| element)
+                        })
+                        .find(|a| {
+                            binomial_is_irreducible(&q_minus_one, degree, |exp| {
+                                tower_pow(&levels, a, exp) == one
+                            })
+                        })?
+                },
+            };
+            levels.push((degree, nonresidue));
+            base_degree = base_degree.checked_mul(degree)?;
+        }
+        Some(
+            levels
+                .into_iter()
+                .map(|(_, nonresidue)| nonresidue)
+                .collect(),
+        )
+    }
+}
+
+/// Returns whether `x^degree - a` is irreducible over the field with `q`
+/// elements, given `q - 1` and a function that returns whether `a^exp = 1`.
+fn binomial_is_irreducible(
+    q_minus_one: &BigUint,
+    degree: u32,
+    mut pow_is_one: impl FnMut(&BigUint) -> bool,
+) -> bool {
+    if degree % 4 == 0 && !(q_minus_one % 4u8).is_zero() {
+        return false;
+    }
+    let mut n = degree;
+    let mut r = 2;
+    while n > 1 {
+        if n % r == 0 {
+            if !(q_minus_one % r).is_zero() || pow_is_one(&(q_minus_one / r)) {
+                return false;
+            }
+            while n % r == 0 {
+                n /= r;
+            }
+        }
+        r += 1;
+    }
+    true
+}
+
+/// Multiplies `a` and `b` in the tower of binomial extensions whose levels
+/// are given by their degrees and non-residues, as returned by
+/// [`PrimeField::build_tower_nonresidues`]. Elements are given by their
+/// coordinates over the prime field.
+fn tower_mul<F: Field>(levels: &[(u32, Vec<F>)], a: &[F], b: &[F]) -> Vec<F> {
+    let ((degree, nonresidue), lower) = match levels.split_last() {
+        Some(split) => split,
+        None => return vec![a[0] * b[0]],
+    };
+    let d = *degree as usize;
+    let n = a.len() / d;
+    let mut result = vec![F::zero(); a.len()];
+    for (i, a_i) in a.chunks(n).enumerate() {
+        for (j, b_j) in b.chunks(n).enumerate() {
+            // `y^d` reduces to the non-residue.
+            let mut product = tower_mul(lower, a_i, b_j);
+            if i + j >= d {
+                product = tower_mul(lower, &product, nonresidue);
+            }
+            let k = (i + j) % d;
+            for (r, p) in result[k * n..(k + 1) * n].iter_mut().zip(product) {
+                *r += p;
+            }
+        }
+    }
+    result
+}
+
+/// Raises `a` to the power `exp` in the tower described by `levels`, as in
+/// [`tower_mul`].
+fn tower_pow<F: Field>(levels: &[(u32, Vec<F>)], a: &[F], exp: &BigUint) -> Vec<F> {
+    let mut result = vec![F::zero(); a.len()];
+    result[0] = F::one();
+    for bit in BitIteratorBE::without_leading_zeros(exp.to_u64_digits()) {
+        result = tower_mul(levels, &result, &result);
+        if bit {
+            result = tower_mul(levels, &result, a);
+        }
+    }
+    result
+}
+
+/// The error returned by [`PrimeField::from_hex_string`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexParseError {
+    /// The string contains no digits.
+    Empty,
+    /// The string contains a character that is not a hexadecimal digit.
+    InvalidDigit,
+    /// The value is not smaller than the modulus.
+    OutOfRange,
+}
+
+impl ark_std::error::Error for HexParseError {}
+
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexParseError::Empty => write!(f, "the string contains no digits"),
+            HexParseError::InvalidDigit => write!(f, "the string contains an invalid digit"),
+            HexParseError::OutOfRange => write!(f, "the value is not smaller than the modulus"),
+        }
+    }
+}
+
+/// The representations of a prime field element, as returned by
+/// [`PrimeField::debug_repr`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldRepr {
+    /// The limbs of the internal representation, least significant first.
+    /// For [`MontBackend`] fields, this is the
+    /// Montgomery form.
+    pub montgomery_limbs: Vec<u64>,
+    /// The limbs of the canonical representative, i.e. of
+    /// [`PrimeField::into_bigint`], least significant first.
+    pub canonical_limbs: Vec<u64>,
+    /// The canonical representative in decimal.
+    pub decimal: String,
+    /// The canonical representative in `0x`-prefixed lower-case hexadecimal.
+    pub hex: String,
+}
+
+impl FieldRepr {
+    pub(crate) fn new<B: BigInteger>(montgomery_limbs: &[u64], canonical: B) -> Self {
+        let canonical_uint: BigUint = canonical.into();
+        Self {
+            montgomery_limbs: montgomery_limbs.to_vec(),
+            canonical_limbs: canonical.as_ref().to_vec(),
+            decimal: canonical_uint.to_string(),
+            hex: format!("{:#x}", canonical_uint),
+        }
+    }
+}
+
+/// Indication of the field element's quadratic residuosity
+///
+/// # Examples
+/// ```
+/// # use ark_std::test_rng;
+/// # use ark_std::UniformRand;
+/// # use ark_test_curves::{LegendreSymbol, Field, bls12_381::Fq as Fp};
+/// let a: Fp = Fp::rand(&mut test_rng());
+/// let b = a.square();
+/// assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum LegendreSymbol {
+    Zero = 0,
+    QuadraticResidue = 1,
+    QuadraticNonResidue = -1,
+}
+
+impl LegendreSymbol {
+    /// Returns true if `self.is_zero()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_std::test_rng;
+    /// # use ark_std::UniformRand;
+    /// # use ark_test_curves::{LegendreSymbol, Field, bls12_381::Fq as Fp};
+    /// let a: Fp = Fp::rand(&mut test_rng());
+    /// let b: Fp = a.square();
+    /// assert!(!b.legendre().is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        *self == LegendreSymbol::Zero
+    }
+
+    /// Returns true if `self` is a quadratic non-residue.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ark_test_curves::{Fp2Config, Field, LegendreSymbol, bls12_381::{Fq, Fq2Config}};
+    /// let a: Fq = Fq2Config::NONRESIDUE;
+    /// assert!(a.legendre().is_qnr());
+    /// ```
+    pub fn is_qnr(&self) -> bool {
+        *self == LegendreSymbol::QuadraticNonResidue
+    }
+
+    /// Returns true if `self` is a quadratic residue.
+    /// # Examples
+    /// ```
+    /// # use ark_std::test_rng;
+    /// # use ark_test_curves::bls12_381::Fq as Fp;
+    /// # use ark_std::UniformRand;
+    /// # use ark_ff::{LegendreSymbol, Field};
+    /// let a: Fp = Fp::rand(&mut test_rng());
+    /// let b: Fp = a.square();
+    /// assert!(b.legendre().is_qr());
+    /// ```
+    pub fn is_qr(&self) -> bool {
+        *self == LegendreSymbol::QuadraticResidue
+    }
+}
+
+/// A [`LegendreSymbol`] is serialized as the single signed byte `-1`, `0` or
+/// `1`.
+impl CanonicalSerialize for LegendreSymbol {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let value: i8 = match self {
+            LegendreSymbol::QuadraticNonResidue => -1,
+            LegendreSymbol::Zero => 0,
+            LegendreSymbol::QuadraticResidue => 1,
+        };
+        (value as u8).serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        1
+    }
+}
+
+impl Valid for LegendreSymbol {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for LegendreSymbol {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        match u8::deserialize_with_mode(reader, compress, validate)? as i8 {
+            -1 => Ok(LegendreSymbol::QuadraticNonResidue),
+            0 => Ok(LegendreSymbol::Zero),
+            1 => Ok(LegendreSymbol::QuadraticResidue),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+/// Precomputation that makes computing square roots faster
+/// A particular variant should only be instantiated if the modulus satisfies
+/// the corresponding condition.
+///
+/// Fields derived with `MontConfig` use [`Self::Cipolla`] when their
+/// two-adicity exceeds [`CIPOLLA_TWO_ADICITY_THRESHOLD`]:
+///
+/// ```
+/// use ark_ff::{
+///     fields::{Field, Fp64, MontBackend, MontConfig},
+///     SqrtPrecomputation, UniformRand,
+/// };
+///
+/// // 3 * 2^41 + 1
+/// #[derive(MontConfig)]
+/// #[modulus = "6597069766657"]
+/// #[generator = "5"]
+/// pub struct FConfig;
+/// pub type F = Fp64<MontBackend<FConfig, 1>>;
+///
+/// assert!(matches!(FConfig::SQRT_PRECOMP, Some(SqrtPrecomputation::Cipolla { .. })));
+///
+/// // Both algorithms find a square root, possibly of opposite sign.
+/// const TONELLI_SHANKS: SqrtPrecomputation<F> = SqrtPrecomputation::TonelliShanks {
+///     two_adicity: 41,
+///     quadratic_nonresidue_to_trace: FConfig::TWO_ADIC_ROOT_OF_UNITY,
+///     trace_of_modulus_minus_one_div_two: &[1],
+/// };
+/// let mut rng = ark_std::test_rng();
+/// for _ in 0..100 {
+///     let a = F::rand(&mut rng).square();
+///     let root = a.sqrt().unwrap();
+///     let expected = TONELLI_SHANKS.sqrt(&a).unwrap();
+///     assert!(root == expected || root == -expected);
+///     assert!((a * F::from(5u8)).sqrt().is_none());
+/// }
+/// ```
+#[non_exhaustive]
 pub enum SqrtPrecomputation<F: Field> {
     // Tonelli-Shanks algorithm works for all elements, no matter what the modulus is.
     TonelliShanks {
@@ -668,10 +2072,24 @@ pub enum SqrtPrecomputation<F: Field> {
     Case3Mod4 {
         modulus_plus_one_div_four: &'static [u64],
     },
+    /// Cipolla's algorithm works for all elements, and unlike Tonelli-Shanks,
+    /// its cost does not grow with the two-adicity of the field. Here `q` is
+    /// the order of the field.
+    Cipolla {
+        field_order_plus_one_div_two: &'static [u64],
+    },
 }
 
+/// The two-adicity above which fields derived with `MontConfig` compute
+/// square roots with [`SqrtPrecomputation::Cipolla`] instead of
+/// [`SqrtPrecomputation::TonelliShanks`], whose cost grows quadratically with
+/// the two-adicity in the worst case.
+pub const CIPOLLA_TWO_ADICITY_THRESHOLD: u32 = 40;
+
 impl<F: Field> SqrtPrecomputation<F> {
-    fn sqrt(&self, elem: &F) -> Option<F> {
+    /// Computes a square root of `elem` using this precomputation, if one
+    /// exists.
+    pub fn sqrt(&self, elem: &F) -> Option<F> {
         match self {
             Self::TonelliShanks {
                 two_adicity,
@@ -711,10 +2129,7 @@ impl<F: Field> SqrtPrecomputation<F> {
                         return None;
                     }
                     let j = v - k;
-                    w = z;
-                    for _ in 1..j {
-                        w.square_in_place();
-                    }
+                    w = z.pow2k(j - 1);
 
                     z = w.square();
                     b *= &z;
@@ -737,6 +2152,38 @@ impl<F: Field> SqrtPrecomputation<F> {
                 let result = elem.pow(modulus_plus_one_div_four.as_ref());
                 (result.square() == *elem).then_some(result)
             },
+            Self::Cipolla {
+                field_order_plus_one_div_two,
+            } => {
+                if elem.is_zero() {
+                    return Some(F::zero());
+                }
+                if !elem.legendre().is_qr() {
+                    return None;
+                }
+                // Find `a` such that `d = a^2 - elem` is a quadratic non-residue, so
+                // that `F[w] / (w^2 - d)` is the quadratic extension of `F`.
+                let (a, d) = (0u64..)
+                    .map(|i| {
+                        let a = F::from(i);
+                        (a, a.square() - elem)
+                    })
+                    .find(|(_, d)| d.legendre().is_qnr())
+                    .unwrap();
+                // Then `(a + w)^((q + 1) / 2)` lies in `F` and is a square root of `elem`.
+                let mul =
+                    |(x0, x1): (F, F), (y0, y1): (F, F)| (x0 * y0 + x1 * y1 * d, x0 * y1 + x1 * y0);
+                let mut result = (F::one(), F::zero());
+                for bit in BitIteratorBE::without_leading_zeros(field_order_plus_one_div_two) {
+                    result = mul(result, result);
+                    if bit {
+                        result = mul(result, (a, F::one()));
+                    }
+                }
+                debug_assert!(result.1.is_zero());
+                debug_assert_eq!(result.0.square(), *elem);
+                Some(result.0)
+            },
         }
     }
 }
@@ -757,7 +2204,14 @@ impl<Slice: AsRef<[u64]>> BitIteratorBE<Slice> {
     /// Construct an iterator that automatically skips any leading zeros.
     /// That is, it skips all zeros before the most-significant one.
     pub fn without_leading_zeros(s: Slice) -> impl Iterator<Item = bool> {
-        Self::new(s).skip_while(|b| !b)
+        Self::without_leading_zeros_with_len(s).1
+    }
+
+    /// Like [`Self::without_leading_zeros`], but also returns the number of
+    /// bits the iterator yields, i.e. [`significant_bits`] of `s`.
+    pub fn without_leading_zeros_with_len(s: Slice) -> (usize, impl Iterator<Item = bool>) {
+        let n = significant_bits(s.as_ref());
+        (n, BitIteratorBE { s, n })
     }
 }
 
@@ -795,15 +2249,8 @@ impl<Slice: AsRef<[u64]>> BitIteratorLE<Slice> {
     /// Construct an iterator that automatically skips any trailing zeros.
     /// That is, it skips all zeros after the most-significant one.
     pub fn without_trailing_zeros(s: Slice) -> impl Iterator<Item = bool> {
-        let mut first_trailing_zero = 0;
-        for (i, limb) in s.as_ref().iter().enumerate().rev() {
-            first_trailing_zero = i * 64 + (64 - limb.leading_zeros()) as usize;
-            if *limb != 0 {
-                break;
-            }
-        }
         let mut iter = Self::new(s);
-        iter.max_len = first_trailing_zero;
+        iter.max_len = significant_bits(iter.s.as_ref());
         iter
     }
 }
@@ -824,22 +2271,119 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
     }
 }
 
+/// Returns the bit length of the little-endian integer `s`, i.e. one more
+/// than the index of its most-significant set bit, or `0` if `s` is zero.
+///
+/// Only the most-significant nonzero limb is inspected bit by bit, so this
+/// is much cheaper than counting the output of
+/// [`BitIteratorBE::without_leading_zeros`].
+pub fn significant_bits(s: &[u64]) -> usize {
+    s.iter()
+        .rposition(|limb| *limb != 0)
+        .map_or(0, |i| i * 64 + (64 - s[i].leading_zeros()) as usize)
+}
+
+/// Splits the significant limbs of `exp` into at most `num_threads` windows
+/// of equal length, except for the last one, least significant first.
+#[cfg(feature = "parallel")]
+fn pow_parallel_windows(exp: &[u64], num_threads: usize) -> Vec<&[u64]> {
+    let num_limbs = (significant_bits(exp) + 63) / 64;
+    let num_threads = max(num_threads, 1);
+    let window_limbs = max((num_limbs + num_threads - 1) / num_threads, 1);
+    exp[..num_limbs].chunks(window_limbs).collect()
+}
+
 // Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
     batch_inversion_and_mul(v, &F::one());
 }
 
-#[cfg(not(feature = "parallel"))]
+/// Returns the number of nonzero elements of `v`, i.e. the number of elements
+/// that [`batch_inversion`] actually inverts, as it skips zeros.
+pub fn count_invertible<F: Field>(v: &[F]) -> usize {
+    v.iter().filter(|x| !x.is_zero()).count()
+}
+
+/// Returns the powers `[1, base, ..., base^(m - 1)]` together with their
+/// inverses, as used for the lookup tables of baby-step giant-step and other
+/// small-range discrete logarithm algorithms.
+///
+/// The inverses are computed with a single [`batch_inversion`], so as there,
+/// the inverse of a zero power is zero.
+pub fn power_table_with_inverses<F: Field>(base: &F, m: usize) -> (Vec<F>, Vec<F>) {
+    let mut powers = Vec::with_capacity(m);
+    let mut power = F::one();
+    for _ in 0..m {
+        powers.push(power);
+        power *= base;
+    }
+    let mut inverses = powers.clone();
+    batch_inversion(&mut inverses);
+    (powers, inverses)
+}
+
+/// Returns the negative powers `[base^(-1), base^(-2), ..., base^(-k)]`,
+/// computed with a single inversion followed by `k - 1` multiplications, or
+/// `None` if `base` is zero.
+pub fn negative_power_table<F: Field>(base: &F, k: usize) -> Option<Vec<F>> {
+    let base_inv = base.inverse()?;
+    let mut power = F::one();
+    Some(
+        (0..k)
+            .map(|_| {
+                power *= base_inv;
+                power
+            })
+            .collect(),
+    )
+}
+
+/// The default minimum number of elements that [`batch_inversion_and_mul`]
+/// hands to each thread when the `parallel` feature is enabled.
+pub const DEFAULT_BATCH_INVERSION_MIN_ELEMENTS_PER_THREAD: usize = 1024;
+
+/// The minimum number of elements that the parallel versions of cheap
+/// elementwise operations, such as [`batch_frobenius_map`], hand to each
+/// thread, so that short inputs are not split across every thread.
+#[cfg(feature = "parallel")]
+const ELEMENTWISE_MIN_ELEMENTS_PER_THREAD: usize = 1024;
+
 // Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+    batch_inversion_and_mul_with_threshold(
+        v,
+        coeff,
+        DEFAULT_BATCH_INVERSION_MIN_ELEMENTS_PER_THREAD,
+    );
+}
+
+#[cfg(not(feature = "parallel"))]
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+///
+/// With the `parallel` feature, `v` is split into chunks of at least
+/// `min_elements_per_thread` elements, which are inverted in parallel. Without
+/// it, `min_elements_per_thread` is ignored.
+pub fn batch_inversion_and_mul_with_threshold<F: Field>(
+    v: &mut [F],
+    coeff: &F,
+    _min_elements_per_thread: usize,
+) {
     serial_batch_inversion_and_mul(v, coeff);
 }
 
 #[cfg(feature = "parallel")]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
-pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+///
+/// With the `parallel` feature, `v` is split into chunks of at least
+/// `min_elements_per_thread` elements, which are inverted in parallel. Without
+/// it, `min_elements_per_thread` is ignored.
+pub fn batch_inversion_and_mul_with_threshold<F: Field>(
+    v: &mut [F],
+    coeff: &F,
+    min_elements_per_thread: usize,
+) {
     // Divide the vector v evenly between all available cores
-    let min_elements_per_thread = 1;
+    let min_elements_per_thread = max(min_elements_per_thread, 1);
     let num_cpus_available = rayon::current_num_threads();
     let num_elems = v.len();
     let num_elem_per_thread = max(num_elems / num_cpus_available, min_elements_per_thread);
@@ -850,6 +2394,235 @@ pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     });
 }
 
+#[cfg(not(feature = "parallel"))]
+// Given a vector of field elements {v_i}, compute the vector {legendre(v_i)}
+pub fn batch_legendre<F: PrimeField>(v: &[F]) -> Vec<LegendreSymbol> {
+    v.iter().map(F::legendre).collect()
+}
+
+#[cfg(feature = "parallel")]
+// Given a vector of field elements {v_i}, compute the vector {legendre(v_i)}
+pub fn batch_legendre<F: PrimeField>(v: &[F]) -> Vec<LegendreSymbol> {
+    // Divide the vector v evenly between all available cores
+    let min_elements_per_thread = 1;
+    let num_cpus_available = rayon::current_num_threads();
+    let num_elems = v.len();
+    let num_elem_per_thread = max(num_elems / num_cpus_available, min_elements_per_thread);
+
+    v.par_chunks(num_elem_per_thread)
+        .flat_map_iter(|chunk| chunk.iter().map(F::legendre))
+        .collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+// Given a vector of field elements {v_i}, compute the vector {v_i^2} in place
+pub fn batch_square_in_place<F: Field>(v: &mut [F]) {
+    v.iter_mut().for_each(|x| {
+        x.square_in_place();
+    });
+}
+
+#[cfg(feature = "parallel")]
+// Given a vector of field elements {v_i}, compute the vector {v_i^2} in place
+pub fn batch_square_in_place<F: Field>(v: &mut [F]) {
+    v.par_iter_mut().for_each(|x| {
+        x.square_in_place();
+    });
+}
+
+// Given a vector of field elements {v_i}, compute the vector {v_i^2}
+pub fn batch_square<F: Field>(v: &[F]) -> Vec<F> {
+    let mut squares = v.to_vec();
+    batch_square_in_place(&mut squares);
+    squares
+}
+
+#[cfg(not(feature = "parallel"))]
+// Given a vector of field elements {v_i} and a scalar c, compute the vector
+// {c * v_i} in place
+pub fn batch_scale<F: Field>(v: &mut [F], scalar: F) {
+    v.iter_mut().for_each(|x| *x *= scalar);
+}
+
+#[cfg(feature = "parallel")]
+// Given a vector of field elements {v_i} and a scalar c, compute the vector
+// {c * v_i} in place
+pub fn batch_scale<F: Field>(v: &mut [F], scalar: F) {
+    v.par_iter_mut().for_each(|x| *x *= scalar);
+}
+
+/// Multiplies every element of `v` by `num / den`, computing the rational
+/// once with a single inversion. Returns `None`, leaving `v` unchanged, if
+/// `den` is zero modulo the characteristic.
+pub fn batch_scale_rational<F: PrimeField>(v: &mut [F], num: i64, den: i64) -> Option<()> {
+    let scalar = F::from(num) * F::from(den).inverse()?;
+    batch_scale(v, scalar);
+    Some(())
+}
+
+#[cfg(not(feature = "parallel"))]
+// Given a vector of field elements {v_i}, apply `frobenius_map(power)` to each v_i
+pub fn batch_frobenius_map<F: Field>(v: &mut [F], power: usize) {
+    v.iter_mut().for_each(|x| x.frobenius_map(power));
+}
+
+#[cfg(feature = "parallel")]
+// Given a vector of field elements {v_i}, apply `frobenius_map(power)` to each v_i
+pub fn batch_frobenius_map<F: Field>(v: &mut [F], power: usize) {
+    // Divide the vector v evenly between all available cores
+    let num_cpus_available = rayon::current_num_threads();
+    let num_elems = v.len();
+    let num_elem_per_thread = max(
+        num_elems / num_cpus_available,
+        ELEMENTWISE_MIN_ELEMENTS_PER_THREAD,
+    );
+
+    v.par_chunks_mut(num_elem_per_thread).for_each(|chunk| {
+        chunk.iter_mut().for_each(|x| x.frobenius_map(power));
+    });
+}
+
+#[cfg(not(feature = "parallel"))]
+// Returns `[start, start + step, ..., start + (count - 1) * step]`, computed by
+// repeatedly adding `step`
+pub fn arithmetic_progression<F: Field>(start: F, step: F, count: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(count);
+    let mut cur = start;
+    for _ in 0..count {
+        result.push(cur);
+        cur += step;
+    }
+    result
+}
+
+#[cfg(feature = "parallel")]
+// Returns `[start, start + step, ..., start + (count - 1) * step]`, computed by
+// repeatedly adding `step`
+pub fn arithmetic_progression<F: Field>(start: F, step: F, count: usize) -> Vec<F> {
+    // Divide the progression evenly between all available cores
+    let num_cpus_available = rayon::current_num_threads();
+    let num_elem_per_thread = max(
+        count / num_cpus_available,
+        ELEMENTWISE_MIN_ELEMENTS_PER_THREAD,
+    );
+
+    let mut result = vec![F::zero(); count];
+    result
+        .par_chunks_mut(num_elem_per_thread)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            // Each chunk starts at `start + offset * step`
+            let offset = (i * num_elem_per_thread) as u64;
+            let mut cur = start + step * F::from(offset);
+            for x in chunk {
+                *x = cur;
+                cur += step;
+            }
+        });
+    result
+}
+
+/// Sets `dst[i] = src[i]` for every `i` such that `choices[i]` is set, and
+/// leaves `dst[i]` unchanged otherwise.
+///
+/// Every element is processed with [`ConditionallySelectable::conditional_assign`],
+/// so neither the control flow nor the memory accesses depend on `choices`.
+///
+/// # Panics
+///
+/// Panics if `dst`, `src` and `choices` do not all have the same length.
+pub fn batch_conditional_assign<F: Field + ConditionallySelectable>(
+    dst: &mut [F],
+    src: &[F],
+    choices: &[subtle::Choice],
+) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "`dst` and `src` must have the same length"
+    );
+    assert_eq!(
+        dst.len(),
+        choices.len(),
+        "`dst` and `choices` must have the same length"
+    );
+    for ((d, s), choice) in dst.iter_mut().zip(src).zip(choices) {
+        d.conditional_assign(s, *choice);
+    }
+}
+
+/// Returns `[base^e for e in exps]`, where each exponent is given by its
+/// `u64` limbs, least significant first.
+///
+/// The table `[base, base^2, base^4, ...]` needed by
+/// [`Field::pow_with_table`] is computed once, up to the bit length of the
+/// largest exponent, so the squarings are shared by all exponents.
+pub fn pow_shared_base<F: Field, S: AsRef<[u64]>>(base: &F, exps: &[S]) -> Vec<F> {
+    let num_bits = exps
+        .iter()
+        .map(|exp| significant_bits(exp.as_ref()))
+        .max()
+        .unwrap_or(0);
+    let mut powers_of_2 = Vec::with_capacity(num_bits);
+    let mut power = *base;
+    for _ in 0..num_bits {
+        powers_of_2.push(power);
+        power.square_in_place();
+    }
+    exps.iter()
+        .map(|exp| F::pow_with_table(&powers_of_2, exp).unwrap())
+        .collect()
+}
+
+/// Returns the common value of the elements of `v` if they are all equal, and
+/// `None` otherwise or if `v` is empty.
+///
+/// This returns as soon as a mismatch is found; see [`ct_all_equal`] for a
+/// variant that examines every element.
+pub fn all_equal<F: Field>(v: &[F]) -> Option<F> {
+    let (first, rest) = v.split_first()?;
+    rest.iter().all(|x| x == first).then(|| *first)
+}
+
+/// Returns the first index at which `a` and `b` differ, or `None` if they
+/// are equal. If one is a strict prefix of the other, this is the length of
+/// the shorter one.
+pub fn first_difference<F: Field>(a: &[F], b: &[F]) -> Option<usize> {
+    a.iter()
+        .zip(b)
+        .position(|(a, b)| a != b)
+        .or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len())))
+}
+
+/// Evaluates the multilinear extension of `evals` at `point`.
+///
+/// `evals[b]` is the value at the boolean hypercube point whose `i`-th
+/// coordinate is the `i`-th bit of `b`, so `point[0]` corresponds to the
+/// least significant bit. The variables are bound one at a time in `O(2^n)`
+/// operations, where `n = point.len()`.
+///
+/// # Panics
+///
+/// Panics if `evals.len() != 2^point.len()`.
+pub fn multilinear_eval<F: Field>(evals: &[F], point: &[F]) -> F {
+    assert!(
+        point.len() < usize::BITS as usize && evals.len() == 1 << point.len(),
+        "expected 2^{} evaluations, got {}",
+        point.len(),
+        evals.len()
+    );
+    let mut evals = evals.to_vec();
+    for x in point {
+        let half = evals.len() / 2;
+        for i in 0..half {
+            let (lo, hi) = (evals[2 * i], evals[2 * i + 1]);
+            evals[i] = lo + (hi - lo) * x;
+        }
+        evals.truncate(half);
+    }
+    evals[0]
+}
+
 /// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
 /// This method is explicitly single-threaded.
 fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
@@ -889,6 +2662,184 @@ fn serial_batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     }
 }
 
+/// Given the evaluations `{f(x_i)}` of a polynomial `f` over the coset
+/// `x_i = offset * omega^i`, where `omega` is a root of unity of order
+/// `evals.len()`, compute `{f(x_i) / Z(x_i)}` in place, where
+/// `Z(X) = X^domain_size - 1` is the vanishing polynomial of the subgroup of
+/// size `domain_size`.
+///
+/// All evaluations of `Z` are inverted with a single batch inversion.
+/// Returns `None`, leaving `evals` untouched, if some `x_i` is a root of `Z`,
+/// or if there is no root of unity of order `evals.len()`.
+pub fn batch_divide_by_vanishing<F: FftField>(
+    evals: &mut [F],
+    offset: F,
+    domain_size: u64,
+) -> Option<()> {
+    if evals.is_empty() {
+        return Some(());
+    }
+    let omega = F::get_root_of_unity(evals.len() as u64)?;
+    // Z(x_i) = offset^n * (omega^n)^i - 1, which is periodic in `i`
+    // with period equal to the order of `omega^n`.
+    let omega_to_n = omega.pow([domain_size]);
+    let mut offset_to_n_omega_to_ni = offset.pow([domain_size]);
+    let mut z_evals = Vec::new();
+    loop {
+        let z = offset_to_n_omega_to_ni - F::one();
+        if z.is_zero() {
+            return None;
+        }
+        z_evals.push(z);
+        offset_to_n_omega_to_ni *= &omega_to_n;
+        if z_evals.len() == evals.len() || offset_to_n_omega_to_ni - F::one() == z_evals[0] {
+            break;
+        }
+    }
+    batch_inversion(&mut z_evals);
+    for (eval, z_inv) in evals.iter_mut().zip(z_evals.iter().cycle()) {
+        *eval *= z_inv;
+    }
+    Some(())
+}
+
+/// Given distinct points `{x_i}` and values `{y_i}`, compute the coefficients
+/// `{f[x_0, ..., x_i]}` of the Newton form of the interpolating polynomial
+/// `f(X) = sum_i f[x_0, ..., x_i] * (X - x_0) * ... * (X - x_{i-1})`.
+///
+/// The differences `x_i - x_j` of all pairs of points are inverted with a
+/// single batch inversion. Returns `None` if the points are not distinct.
+///
+/// # Panics
+///
+/// Panics if `points` and `values` have different lengths.
+pub fn divided_differences<F: Field>(points: &[F], values: &[F]) -> Option<Vec<F>> {
+    assert_eq!(points.len(), values.len());
+    let n = points.len();
+    // The denominators `x_i - x_{i-k}` of level `k`, for `i` in `k..n`.
+    let mut denominators = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+    for k in 1..n {
+        denominators.extend((k..n).map(|i| points[i] - points[i - k]));
+    }
+    if denominators.iter().any(F::is_zero) {
+        return None;
+    }
+    batch_inversion(&mut denominators);
+
+    // After level `k`, `coeffs[i] = f[x_{i-k}, ..., x_i]` for `i >= k`.
+    let mut coeffs = values.to_vec();
+    let mut inverses = denominators.as_slice();
+    for k in 1..n {
+        let (level, rest) = inverses.split_at(n - k);
+        for i in (k..n).rev() {
+            coeffs[i] = (coeffs[i] - coeffs[i - 1]) * level[i - k];
+        }
+        inverses = rest;
+    }
+    Some(coeffs)
+}
+
+/// Returns the sign of the permutation `i -> perm[i]` of `0..perm.len()` as a
+/// field element, i.e. `1` if it is even and `-1` if it is odd.
+///
+/// The parity is computed from the cycle decomposition: a cycle of length `l`
+/// is a product of `l - 1` transpositions.
+///
+/// # Panics
+///
+/// Panics if `perm` is not a permutation of `0..perm.len()`.
+pub fn permutation_sign<F: Field>(perm: &[usize]) -> F {
+    let mut visited = ark_std::vec![false; perm.len()];
+    let mut is_odd = false;
+    for start in 0..perm.len() {
+        if visited[start] {
+            continue;
+        }
+        let mut i = start;
+        let mut cycle_len = 0;
+        while !visited[i] {
+            visited[i] = true;
+            i = perm[i];
+            assert!(i < perm.len(), "not a permutation");
+            cycle_len += 1;
+        }
+        assert_eq!(i, start, "not a permutation");
+        is_odd ^= cycle_len % 2 == 0;
+    }
+    if is_odd {
+        F::NEG_ONE
+    } else {
+        F::ONE
+    }
+}
+
+/// Returns a permutation of `0..n` that is derived deterministically from
+/// `seed`, for reproducible shuffles in tests and test vectors.
+///
+/// This is a Fisher-Yates shuffle of the identity permutation: for `i` from
+/// `n - 1` down to `1`, the entry at `i` is swapped with the entry at
+/// `seed^k mod (i + 1)`, where `k = n - i` and `seed^k` is read as an integer.
+/// The shuffle is not uniform, and should not be used where unpredictability
+/// matters.
+pub fn fisher_yates_permutation<F: PrimeField>(n: usize, seed: F) -> Vec<usize> {
+    let mut perm = (0..n).collect::<Vec<_>>();
+    let mut power = F::one();
+    for i in (1..n).rev() {
+        power *= seed;
+        let bound = F::BigInt::from(i as u64 + 1);
+        let (_, j) = power.into_bigint().div_rem(&bound);
+        perm.swap(i, j.as_ref()[0] as usize);
+    }
+    perm
+}
+
+/// Returns, for each nonzero element `v_i` of `v`, the index `k` in
+/// `0..domain_size` of the coset `GENERATOR^k * G` that contains it, where `G`
+/// is the subgroup of index `domain_size` in the multiplicative group, i.e. the
+/// subgroup of `domain_size`-th powers. Equivalently, `k` is the discrete
+/// logarithm of `v_i` to the base `GENERATOR`, reduced modulo `domain_size`.
+///
+/// Writing `q` for the order of the field, the index is found by raising
+/// `v_i` to the power `(q - 1) / domain_size`, which maps it into the subgroup
+/// of order `domain_size`, and looking the result up among the powers of
+/// `GENERATOR^((q - 1) / domain_size)`. The lookup is linear in
+/// `domain_size`, so only small domain sizes are supported.
+///
+/// # Panics
+///
+/// Panics if `domain_size` is zero, larger than `2^16`, or does not divide
+/// `q - 1`, or if `v` contains zero.
+pub fn batch_coset_reduce<F: FftField>(v: &[F], domain_size: u64) -> Vec<u64> {
+    assert!(
+        (1..=1 << 16).contains(&domain_size),
+        "domain size must be between 1 and 2^16"
+    );
+    let characteristic = F::characteristic_biguint();
+    let q_minus_one = characteristic.pow(F::extension_degree() as u32) - 1u8;
+    assert!(
+        (&q_minus_one % domain_size).is_zero(),
+        "domain size must divide the order of the multiplicative group"
+    );
+    let exp = (q_minus_one / domain_size).to_u64_digits();
+
+    // `roots[k] = omega^k` for a primitive `domain_size`-th root of unity `omega`.
+    let omega = F::GENERATOR.pow(&exp);
+    let mut roots = Vec::with_capacity(domain_size as usize);
+    let mut root = F::one();
+    for _ in 0..domain_size {
+        roots.push(root);
+        root *= omega;
+    }
+
+    v.iter()
+        .map(|v_i| {
+            assert!(!v_i.is_zero(), "zero lies in no coset");
+            let power = v_i.pow(&exp);
+            roots.iter().position(|root| *root == power).unwrap() as u64
+        })
+        .collect()
+}
+
 #[cfg(all(test, feature = "std"))]
 mod std_tests {
     use super::BitIteratorLE;
@@ -915,7 +2866,405 @@ mod no_std_tests {
     // TODO: only Fr & FrConfig should need to be imported.
     // The rest of imports are caused by cargo not resolving the deps properly
     // from this crate and from ark_test_curves
-    use ark_test_curves::{batch_inversion, batch_inversion_and_mul, bls12_381::Fr, PrimeField};
+    use ark_test_curves::{
+        all_equal, arithmetic_progression, batch_conditional_assign, batch_coset_reduce,
+        batch_divide_by_vanishing, batch_frobenius_map, batch_inversion, batch_inversion_and_mul,
+        batch_inversion_and_mul_with_threshold, batch_legendre, batch_scale_rational, batch_square,
+        batch_square_in_place, bls12_381::Fr, count_invertible, divided_differences, field,
+        first_difference, fisher_yates_permutation, multilinear_eval, negative_power_table,
+        permutation_sign, pow_shared_base, power_table_with_inverses, FftField, Field,
+        LegendreSymbol, PrimeField,
+    };
+
+    #[test]
+    fn test_significant_bits() {
+        use ark_std::{rand::Rng, vec::Vec};
+
+        assert_eq!(significant_bits(&[]), 0);
+        assert_eq!(significant_bits(&[0, 0, 0]), 0);
+        assert_eq!(significant_bits(&[1]), 1);
+        assert_eq!(significant_bits(&[0, 1 << 10, 0]), 75);
+        assert_eq!(significant_bits(&[0, u64::MAX]), 128);
+
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let mut limbs = [0u64; 4];
+            let shift = rng.gen_range(0..64);
+            for limb in &mut limbs[..rng.gen_range(0..=4)] {
+                *limb = rng.gen::<u64>() >> shift;
+            }
+            let (len, bits) = BitIteratorBE::without_leading_zeros_with_len(limbs);
+            let bits = bits.collect::<Vec<_>>();
+            assert_eq!(len, bits.len());
+            assert_eq!(
+                significant_bits(&limbs),
+                BitIteratorBE::new(limbs).skip_while(|b| !b).count()
+            );
+            assert_eq!(BitIteratorLE::without_trailing_zeros(limbs).count(), len);
+            assert!(bits.first().map_or(true, |b| *b));
+        }
+    }
+
+    #[test]
+    fn test_first_difference() {
+        let mut rng = test_rng();
+        let a: Vec<Fr> = (0..100).map(|_| Fr::rand(&mut rng)).collect();
+        let mut b = a.clone();
+        assert_eq!(first_difference::<Fr>(&[], &[]), None);
+        assert_eq!(first_difference(&a, &b), None);
+
+        for i in [0, 37, 99] {
+            b[i] += Fr::one();
+            assert_eq!(first_difference(&a, &b), Some(i));
+            b[i] = a[i];
+        }
+
+        assert_eq!(first_difference(&a, &b[..99]), Some(99));
+        assert_eq!(first_difference(&a[..50], &b), Some(50));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_pow_parallel() {
+        use ark_std::rand::Rng;
+        let mut rng = test_rng();
+        for num_limbs in [0, 1, 2, 4, 16] {
+            let f = Fr::rand(&mut rng);
+            let exp: Vec<u64> = (0..num_limbs).map(|_| rng.gen()).collect();
+            assert_eq!(f.pow_parallel(&exp), f.pow(&exp));
+        }
+        let f = Fr::rand(&mut rng);
+        assert_eq!(f.pow_parallel([u64::MAX; 8]), f.pow([u64::MAX; 8]));
+        assert_eq!(f.pow_parallel([0u64, 0, 1]), f.pow([0u64, 0, 1]));
+
+        // A large exponent is split across all threads of the pool.
+        let exp: Vec<u64> = (0..16).map(|_| rng.gen()).collect();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        pool.install(|| {
+            let windows = pow_parallel_windows(&exp, rayon::current_num_threads());
+            assert_eq!(windows.len(), 4);
+            assert_eq!(windows.concat(), exp);
+            assert_eq!(f.pow_parallel(&exp), f.pow(&exp));
+
+            // Windows of uneven length, and leading zero limbs.
+            let mut exp: Vec<u64> = (0..7).map(|_| rng.gen()).collect();
+            exp.extend([0, 0]);
+            let windows = pow_parallel_windows(&exp, rayon::current_num_threads());
+            assert_eq!(
+                windows.iter().map(|w| w.len()).collect::<Vec<_>>(),
+                [2, 2, 2, 1]
+            );
+            assert_eq!(f.pow_parallel(&exp), f.pow(&exp));
+        });
+        // Small exponents are not split.
+        assert_eq!(pow_parallel_windows(&[u64::MAX], 4).len(), 1);
+        assert!(pow_parallel_windows(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_batch_scale_rational() {
+        let mut rng = test_rng();
+        let v: Vec<Fr> = (0..100).map(|_| Fr::rand(&mut rng)).collect();
+        for (num, den) in [
+            (1, 1),
+            (3, 4),
+            (-5, 7),
+            (2, -9),
+            (0, 3),
+            (i64::MIN, i64::MAX),
+        ] {
+            let rational = Fr::from(num) / Fr::from(den);
+            let mut w = v.clone();
+            assert_eq!(batch_scale_rational(&mut w, num, den), Some(()));
+            assert!(w.iter().zip(&v).all(|(w, v)| *w == *v * rational));
+        }
+
+        let mut w = v.clone();
+        assert_eq!(batch_scale_rational(&mut w, 1, 0), None);
+        assert_eq!(w, v);
+    }
+
+    #[test]
+    fn test_negative_power_table() {
+        let mut rng = test_rng();
+        let base = Fr::rand(&mut rng);
+        let table = negative_power_table(&base, 20).unwrap();
+        assert_eq!(table.len(), 20);
+        for (i, power) in table.iter().enumerate() {
+            assert_eq!(*power, base.pow([i as u64 + 1]).inverse().unwrap());
+        }
+        assert_eq!(negative_power_table(&base, 0), Some(vec![]));
+        assert_eq!(negative_power_table(&Fr::zero(), 5), None);
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(utils::crc32(b""), 0);
+        assert_eq!(utils::crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(
+            utils::crc32(b"The quick brown fox jumps over the lazy dog"),
+            0x414F_A339
+        );
+    }
+
+    #[test]
+    fn test_field_macro() {
+        use ark_std::str::FromStr;
+        assert_eq!(field!(Fr, "0"), Fr::zero());
+        assert_eq!(field!(Fr, "0x2a"), Fr::from(42u64));
+        assert_eq!(field!(Fr, "-0X2A"), -Fr::from(42u64));
+        assert_eq!(
+            field!(
+                Fr,
+                "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+            ),
+            Fr::NEG_ONE
+        );
+        assert_eq!(field!(Fr, "123456789"), Fr::from_str("123456789").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "value is not smaller than the modulus")]
+    fn test_field_macro_unreduced() {
+        let _ = field!(
+            Fr,
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+        );
+    }
+
+    #[test]
+    fn test_batch_square() {
+        let mut rng = test_rng();
+        for len in [0, 1, 7, 1000] {
+            let v: Vec<Fr> = (0..len).map(|_| Fr::rand(&mut rng)).collect();
+            let mut expected = v.clone();
+            for x in &mut expected {
+                x.square_in_place();
+            }
+            assert_eq!(batch_square(&v), expected);
+            let mut squared = v.clone();
+            batch_square_in_place(&mut squared);
+            assert_eq!(squared, expected);
+        }
+
+        use ark_test_curves::bls12_381::Fq2;
+        let mut v: Vec<Fq2> = (0..1000).map(|_| Fq2::rand(&mut rng)).collect();
+        let expected: Vec<Fq2> = v.iter().map(Field::square).collect();
+        batch_square_in_place(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn test_multilinear_eval() {
+        let mut rng = test_rng();
+        assert_eq!(multilinear_eval(&[Fr::from(7u64)], &[]), Fr::from(7u64));
+
+        let n = 4;
+        let evals: Vec<Fr> = (0..1 << n).map(|_| Fr::rand(&mut rng)).collect();
+        // At hypercube points, the stored evaluation is returned.
+        for (b, eval) in evals.iter().enumerate() {
+            let point: Vec<Fr> = (0..n).map(|i| Fr::from(((b >> i) & 1) as u64)).collect();
+            assert_eq!(multilinear_eval(&evals, &point), *eval);
+        }
+
+        // Elsewhere, it agrees with `sum_b evals[b] * eq(b, point)`.
+        let point: Vec<Fr> = (0..n).map(|_| Fr::rand(&mut rng)).collect();
+        let expected: Fr = evals
+            .iter()
+            .enumerate()
+            .map(|(b, eval)| {
+                let eq: Fr = point
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| if (b >> i) & 1 == 1 { *x } else { Fr::one() - x })
+                    .product();
+                *eval * eq
+            })
+            .sum();
+        assert_eq!(multilinear_eval(&evals, &point), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2^2 evaluations, got 3")]
+    fn test_multilinear_eval_wrong_length() {
+        let evals = [Fr::one(); 3];
+        multilinear_eval(&evals, &[Fr::one(), Fr::one()]);
+    }
+
+    #[test]
+    fn test_batch_frobenius_map() {
+        use ark_test_curves::bls12_381::Fq2;
+        let mut rng = test_rng();
+        let v: Vec<Fq2> = (0..1000).map(|_| Fq2::rand(&mut rng)).collect();
+        for power in 0..4 {
+            let mut expected = v.clone();
+            for x in &mut expected {
+                x.frobenius_map(power);
+            }
+            let mut mapped = v.clone();
+            batch_frobenius_map(&mut mapped, power);
+            assert_eq!(mapped, expected);
+        }
+        let mut empty: [Fq2; 0] = [];
+        batch_frobenius_map(&mut empty, 1);
+    }
+
+    #[test]
+    fn test_mont_backend_128() {
+        use ark_test_curves::{
+            bls12_381::{Fq, FqConfig, FrConfig},
+            Fp256, Fp384, MontBackend128,
+        };
+        type Fr128 = Fp256<MontBackend128<FrConfig, 4>>;
+        type Fq128 = Fp384<MontBackend128<FqConfig, 6>>;
+
+        fn check<F: PrimeField, G: PrimeField<BigInt = F::BigInt>>(a: F, b: F) {
+            let to_g = |x: F| G::from_bigint(x.into_bigint()).unwrap();
+            let (c, d) = (to_g(a), to_g(b));
+            assert_eq!((a * b).into_bigint(), (c * d).into_bigint());
+            assert_eq!(a.square().into_bigint(), c.square().into_bigint());
+            assert_eq!((a + b).into_bigint(), (c + d).into_bigint());
+            assert_eq!((a - b).into_bigint(), (c - d).into_bigint());
+            assert_eq!(a.inverse().map(to_g), c.inverse());
+            assert_eq!(a.sqrt().map(to_g), c.sqrt());
+            assert_eq!(
+                F::sum_of_products(&[a, b], &[b, a]).into_bigint(),
+                G::sum_of_products(&[c, d], &[d, c]).into_bigint()
+            );
+        }
+
+        let mut rng = test_rng();
+        for _ in 0..1000 {
+            let a = Fr::rand(&mut rng);
+            let b = Fr::rand(&mut rng);
+            check::<Fr, Fr128>(a, b);
+            // Both backends store the same Montgomery form.
+            let product = Fr128::from_bigint(a.into_bigint()).unwrap()
+                * Fr128::from_bigint(b.into_bigint()).unwrap();
+            assert_eq!((a * b).0, product.0);
+            check::<Fq, Fq128>(Fq::rand(&mut rng), Fq::rand(&mut rng));
+        }
+        for (a, b) in [
+            (Fr::zero(), Fr::one()),
+            (-Fr::one(), -Fr::one()),
+            (Fr::one(), Fr::one()),
+        ] {
+            check::<Fr, Fr128>(a, b);
+        }
+        assert_eq!(Fr128::GENERATOR.into_bigint(), Fr::GENERATOR.into_bigint());
+        assert_eq!(
+            Fr128::TWO_ADIC_ROOT_OF_UNITY.into_bigint(),
+            Fr::TWO_ADIC_ROOT_OF_UNITY.into_bigint()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_progression() {
+        let mut rng = test_rng();
+        let start = Fr::rand(&mut rng);
+        let step = Fr::rand(&mut rng);
+        for count in [0, 1, 2, 7, 1000, 5000] {
+            let progression = arithmetic_progression(start, step, count);
+            assert_eq!(progression.len(), count);
+            for (i, x) in progression.iter().enumerate() {
+                assert_eq!(*x, start + step * Fr::from(i as u64));
+            }
+        }
+        assert_eq!(
+            arithmetic_progression(Fr::one(), Fr::zero(), 5),
+            vec![Fr::one(); 5]
+        );
+    }
+
+    #[test]
+    fn test_batch_conditional_assign() {
+        use ark_std::rand::Rng;
+        use ark_test_curves::bls12_381::Fq2;
+        use subtle::{Choice, ConditionallySelectable};
+        let mut rng = test_rng();
+        let dst: Vec<Fq2> = (0..1000).map(|_| Fq2::rand(&mut rng)).collect();
+        let src: Vec<Fq2> = (0..1000).map(|_| Fq2::rand(&mut rng)).collect();
+
+        let choices: Vec<Choice> = (0..1000)
+            .map(|_| Choice::from(rng.gen::<u8>() & 1))
+            .collect();
+        let mut expected = dst.clone();
+        for ((d, s), choice) in expected.iter_mut().zip(&src).zip(&choices) {
+            if bool::from(*choice) {
+                *d = *s;
+            }
+        }
+        let mut assigned = dst.clone();
+        batch_conditional_assign(&mut assigned, &src, &choices);
+        assert_eq!(assigned, expected);
+
+        // Every element is examined: all of them are assigned when every
+        // choice is set, and none of them when no choice is set.
+        let mut assigned = dst.clone();
+        batch_conditional_assign(&mut assigned, &src, &vec![Choice::from(1); 1000]);
+        assert_eq!(assigned, src);
+        let mut assigned = dst.clone();
+        batch_conditional_assign(&mut assigned, &src, &vec![Choice::from(0); 1000]);
+        assert_eq!(assigned, dst);
+
+        let a = Fr::rand(&mut rng);
+        let b = Fr::rand(&mut rng);
+        assert_eq!(Fr::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(Fr::conditional_select(&a, &b, Choice::from(1)), b);
+    }
+
+    #[test]
+    #[should_panic(expected = "`dst` and `choices` must have the same length")]
+    fn test_batch_conditional_assign_length_mismatch() {
+        let mut dst = [Fr::one(); 2];
+        batch_conditional_assign(&mut dst, &[Fr::zero(); 2], &[subtle::Choice::from(1)]);
+    }
+
+    #[test]
+    fn test_legendre_symbol_serialization() {
+        use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+        use ark_std::vec;
+        for (symbol, byte) in [
+            (LegendreSymbol::QuadraticNonResidue, 0xffu8),
+            (LegendreSymbol::Zero, 0),
+            (LegendreSymbol::QuadraticResidue, 1),
+        ] {
+            let mut bytes = Vec::new();
+            symbol.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(bytes, vec![byte]);
+            assert_eq!(symbol.compressed_size(), 1);
+            assert_eq!(
+                LegendreSymbol::deserialize_compressed(&bytes[..]).unwrap(),
+                symbol
+            );
+        }
+        for byte in [2u8, 0x7f, 0x80, 0xfe] {
+            assert!(LegendreSymbol::deserialize_compressed(&[byte][..]).is_err());
+        }
+        assert!(LegendreSymbol::deserialize_compressed(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_pow_shared_base() {
+        use ark_std::rand::Rng;
+        let mut rng = test_rng();
+        let base = Fr::rand(&mut rng);
+        let mut exps: Vec<Vec<u64>> = (0..20)
+            .map(|i| (0..i % 5).map(|_| rng.gen()).collect())
+            .collect();
+        exps.extend([vec![0], vec![1], vec![0, 0, 1], vec![u64::MAX; 4], vec![]]);
+        let expected: Vec<Fr> = exps.iter().map(|e| base.pow(e)).collect();
+        assert_eq!(pow_shared_base(&base, &exps), expected);
+
+        assert!(pow_shared_base::<Fr, [u64; 1]>(&base, &[]).is_empty());
+        assert_eq!(
+            pow_shared_base(&Fr::zero(), &[[0u64], [3]]),
+            vec![Fr::one(), Fr::zero()]
+        );
+    }
 
     #[test]
     fn test_batch_inversion() {
@@ -940,6 +3289,249 @@ mod no_std_tests {
                 rand_multiplier
             );
         }
+
+        // The result does not depend on how the vector is split up.
+        for min_elements_per_thread in [0, 1, 7, 1024, usize::MAX] {
+            let mut random_coeffs_inv_shifted = random_coeffs.clone();
+            batch_inversion_and_mul_with_threshold(
+                &mut random_coeffs_inv_shifted,
+                &rand_multiplier,
+                min_elements_per_thread,
+            );
+            for i in 0..=vec_size {
+                assert_eq!(
+                    random_coeffs_inv_shifted[i] * random_coeffs[i],
+                    rand_multiplier
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_invertible() {
+        let mut rng = test_rng();
+        assert_eq!(count_invertible::<Fr>(&[]), 0);
+        let mut v = (0..100).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        for i in (0..100).step_by(7) {
+            v[i] = Fr::zero();
+        }
+        assert_eq!(count_invertible(&v), 100 - 15);
+
+        // Exactly the counted elements are inverted.
+        let mut inverses = v.clone();
+        batch_inversion(&mut inverses);
+        let inverted = v
+            .iter()
+            .zip(&inverses)
+            .filter(|(x, x_inv)| (**x * **x_inv).is_one())
+            .count();
+        assert_eq!(count_invertible(&v), inverted);
+    }
+
+    #[test]
+    fn test_power_table_with_inverses() {
+        let mut rng = test_rng();
+        let base = Fr::rand(&mut rng);
+        let (powers, inverses) = power_table_with_inverses(&base, 100);
+        assert_eq!(powers.len(), 100);
+        assert_eq!(inverses.len(), 100);
+        for (i, (power, inverse)) in powers.iter().zip(&inverses).enumerate() {
+            assert_eq!(*power, base.pow([i as u64]));
+            assert_eq!(*power * inverse, Fr::one());
+        }
+
+        assert_eq!(power_table_with_inverses(&base, 0), (vec![], vec![]));
+        let (powers, inverses) = power_table_with_inverses(&Fr::zero(), 3);
+        assert_eq!(powers, vec![Fr::one(), Fr::zero(), Fr::zero()]);
+        assert_eq!(inverses, vec![Fr::one(), Fr::zero(), Fr::zero()]);
+    }
+
+    #[test]
+    fn test_batch_divide_by_vanishing() {
+        let mut rng = test_rng();
+        let coeffs = (0..10).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let evaluate = |x: Fr| coeffs.iter().rev().fold(Fr::zero(), |acc, c| acc * x + c);
+
+        for (coset_size, domain_size) in [(8u64, 4u64), (8, 8), (8, 16), (16, 3)] {
+            let omega = Fr::get_root_of_unity(coset_size).unwrap();
+            let offset = Fr::GENERATOR;
+            let points = (0..coset_size)
+                .map(|i| offset * omega.pow([i]))
+                .collect::<Vec<_>>();
+            let mut evals = points.iter().map(|x| evaluate(*x)).collect::<Vec<_>>();
+            batch_divide_by_vanishing(&mut evals, offset, domain_size).unwrap();
+            for (x, eval) in points.iter().zip(&evals) {
+                let z = x.pow([domain_size]) - Fr::one();
+                assert_eq!(*eval, evaluate(*x) / z);
+            }
+        }
+
+        // Without an offset, the coset contains roots of the vanishing polynomial.
+        let mut evals = (0..8).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let evals_copy = evals.clone();
+        assert!(batch_divide_by_vanishing(&mut evals, Fr::one(), 4).is_none());
+        assert_eq!(evals, evals_copy);
+    }
+
+    #[test]
+    fn test_divided_differences() {
+        // f(X) = X^2 + 1 at 0, 1, 3 is 1 + (X - 0) + (X - 0)(X - 1).
+        let points = [0u64, 1, 3].map(Fr::from);
+        let values = [1u64, 2, 10].map(Fr::from);
+        let expected = [1u64, 1, 1].map(Fr::from);
+        assert_eq!(divided_differences(&points, &values).unwrap(), expected);
+
+        // The Newton form reproduces the values at the points.
+        let mut rng = test_rng();
+        let points = (0..20).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let values = (0..20).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let coeffs = divided_differences(&points, &values).unwrap();
+        for (x, y) in points.iter().zip(&values) {
+            let eval = coeffs
+                .iter()
+                .zip(&points)
+                .rev()
+                .fold(Fr::zero(), |acc, (c, x_i)| acc * (*x - x_i) + c);
+            assert_eq!(eval, *y);
+        }
+
+        assert_eq!(divided_differences::<Fr>(&[], &[]).unwrap(), vec![]);
+        let duplicate_points = [points[0], points[1], points[0]];
+        assert!(divided_differences(&duplicate_points, &values[..3]).is_none());
+    }
+
+    #[test]
+    fn test_permutation_sign() {
+        use ark_std::rand::Rng;
+        assert_eq!(permutation_sign::<Fr>(&[]), Fr::one());
+        assert_eq!(permutation_sign::<Fr>(&[0, 1, 2, 3]), Fr::one());
+        assert_eq!(permutation_sign::<Fr>(&[0, 3, 2, 1]), -Fr::one());
+        assert_eq!(permutation_sign::<Fr>(&[1, 2, 0]), Fr::one());
+        assert_eq!(permutation_sign::<Fr>(&[1, 2, 3, 0]), -Fr::one());
+        // (0 1)(2 3 4)
+        assert_eq!(permutation_sign::<Fr>(&[1, 0, 3, 4, 2]), -Fr::one());
+
+        // The sign agrees with the parity of the number of inversions.
+        let mut rng = test_rng();
+        let mut perm = (0..50).collect::<Vec<usize>>();
+        for _ in 0..20 {
+            for i in (1..perm.len()).rev() {
+                perm.swap(i, rng.gen_range(0..=i));
+            }
+            let inversions = (0..perm.len())
+                .flat_map(|i| (i + 1..perm.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| perm[i] > perm[j])
+                .count();
+            let expected = if inversions % 2 == 0 {
+                Fr::one()
+            } else {
+                -Fr::one()
+            };
+            assert_eq!(permutation_sign::<Fr>(&perm), expected);
+        }
+    }
+
+    #[test]
+    fn test_fisher_yates_permutation() {
+        let mut rng = test_rng();
+        for n in [0, 1, 2, 10, 100] {
+            let seed = Fr::rand(&mut rng);
+            let perm = fisher_yates_permutation(n, seed);
+            assert_eq!(perm, fisher_yates_permutation(n, seed));
+            let mut sorted = perm.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+        }
+
+        // Different seeds give different permutations.
+        assert_ne!(
+            fisher_yates_permutation(100, Fr::from(2u8)),
+            fisher_yates_permutation(100, Fr::from(3u8))
+        );
+        // Swap 2 with `2^1 mod 3 = 2`, then 1 with `2^2 mod 2 = 0`.
+        assert_eq!(fisher_yates_permutation(3, Fr::from(2u8)), vec![1, 0, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permutation_sign_rejects_non_permutation() {
+        permutation_sign::<Fr>(&[0, 0, 1]);
+    }
+
+    #[test]
+    fn test_batch_coset_reduce() {
+        let mut rng = test_rng();
+        for domain_size in [1u64, 2, 3, 8, 12, 1 << 10] {
+            // The index of `GENERATOR^k` is `k mod domain_size`.
+            let powers = (0..2 * domain_size)
+                .map(|k| Fr::GENERATOR.pow([k]))
+                .collect::<Vec<_>>();
+            let expected = (0..2 * domain_size)
+                .map(|k| k % domain_size)
+                .collect::<Vec<_>>();
+            assert_eq!(batch_coset_reduce(&powers, domain_size), expected);
+
+            // Multiplying by a `domain_size`-th power preserves the coset.
+            let v = (0..20).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let w = v
+                .iter()
+                .map(|v_i| *v_i * Fr::rand(&mut rng).pow([domain_size]))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                batch_coset_reduce(&v, domain_size),
+                batch_coset_reduce(&w, domain_size)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_coset_reduce_rejects_non_divisor() {
+        // The multiplicative group of `Fr` has order `2^32 * 3 * 11 * 19 * ...`, which is not divisible by 5.
+        batch_coset_reduce(&[Fr::one()], 5);
+    }
+
+    #[test]
+    fn test_cipolla_sqrt() {
+        use ark_test_curves::{bls12_381::FrConfig, MontConfig, SqrtPrecomputation};
+        const CIPOLLA: SqrtPrecomputation<Fr> = SqrtPrecomputation::Cipolla {
+            field_order_plus_one_div_two: &FrConfig::MODULUS_PLUS_ONE_DIV_TWO.0,
+        };
+        let mut rng = test_rng();
+        assert_eq!(CIPOLLA.sqrt(&Fr::zero()), Some(Fr::zero()));
+        for _ in 0..100 {
+            let a = Fr::rand(&mut rng);
+            let square = a.square();
+            let root = CIPOLLA.sqrt(&square).unwrap();
+            assert!(root == a || root == -a);
+            assert_eq!(CIPOLLA.sqrt(&(square * Fr::GENERATOR)), None);
+        }
+    }
+
+    #[test]
+    fn test_batch_legendre() {
+        let mut rng = test_rng();
+        let mut v = (0..1000).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        v.push(Fr::zero());
+        v.push(Fr::GENERATOR);
+        v.push(Fr::GENERATOR.square());
+
+        let expected = v.iter().map(Fr::legendre).collect::<Vec<_>>();
+        assert_eq!(batch_legendre(&v), expected);
+        assert!(batch_legendre::<Fr>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_all_equal() {
+        let mut rng = test_rng();
+        let a = Fr::rand(&mut rng);
+        assert_eq!(all_equal(&[a; 10]), Some(a));
+        assert_eq!(all_equal(&[a]), Some(a));
+        assert_eq!(all_equal::<Fr>(&[]), None);
+
+        let mut v = vec![a; 10];
+        v[7] += Fr::one();
+        assert_eq!(all_equal(&v), None);
     }
 
     #[test]
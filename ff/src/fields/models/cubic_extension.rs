@@ -11,7 +11,9 @@ use ark_std::{
     vec::Vec,
 };
 
+use num_bigint::BigUint;
 use num_traits::{One, Zero};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 use ark_std::rand::{
@@ -142,6 +144,42 @@ impl<P: CubicExtConfig> CubicExtField<P> {
         assert!(self_to_p.c1.is_zero() && self_to_p.c2.is_zero());
         self_to_p.c0
     }
+
+    /// Sanity-checks the Frobenius coefficients of `P`, for use in a test
+    /// when defining a new extension.
+    ///
+    /// This checks with `debug_assert!` that the `i`-th coefficients are
+    /// `P::NONRESIDUE^((p^i - 1) / 3)` and `P::NONRESIDUE^(2 * (p^i - 1) / 3)`,
+    /// where `p` is the characteristic, that the Frobenius map agrees with
+    /// raising to the `p`-th power, and that applying it
+    /// `P::DEGREE_OVER_BASE_PRIME_FIELD` times is the identity.
+    pub fn validate_frobenius_coeffs() {
+        let p: BigUint = P::BasePrimeField::MODULUS.into();
+        let mut p_pow = BigUint::one();
+        for i in 0..P::FROBENIUS_COEFF_C1.len() {
+            let exp = (&p_pow - 1u64) / 3u64;
+            let c1 = P::NONRESIDUE.pow(exp.to_u64_digits());
+            let mut actual_c1 = P::BaseField::one();
+            let mut actual_c2 = P::BaseField::one();
+            P::mul_base_field_by_frob_coeff(&mut actual_c1, &mut actual_c2, i);
+            debug_assert_eq!(actual_c1, c1, "wrong FROBENIUS_COEFF_C1[{}]", i);
+            debug_assert_eq!(actual_c2, c1.square(), "wrong FROBENIUS_COEFF_C2[{}]", i);
+            p_pow *= &p;
+        }
+
+        let x = Self::new(
+            P::BaseField::from(2u64),
+            P::BaseField::from(3u64),
+            P::BaseField::from(5u64),
+        );
+        let mut y = x;
+        y.frobenius_map(1);
+        debug_assert_eq!(y, x.pow(p.to_u64_digits()), "Frobenius map is not x -> x^p");
+        for _ in 1..P::DEGREE_OVER_BASE_PRIME_FIELD {
+            y.frobenius_map(1);
+        }
+        debug_assert_eq!(y, x, "Frobenius map does not have the expected order");
+    }
 }
 
 impl<P: CubicExtConfig> Zero for CubicExtField<P> {
@@ -175,6 +213,12 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
 
     const ONE: Self = Self::new(P::BaseField::ONE, P::BaseField::ZERO, P::BaseField::ZERO);
 
+    const NEG_ONE: Self = Self::new(
+        P::BaseField::NEG_ONE,
+        P::BaseField::ZERO,
+        P::BaseField::ZERO,
+    );
+
     fn extension_degree() -> u64 {
         3 * P::BaseField::extension_degree()
     }
@@ -192,6 +236,16 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         )
     }
 
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+
+    fn vartime_eq(&self, other: &Self) -> bool {
+        self.c0.vartime_eq(&other.c0)
+            && self.c1.vartime_eq(&other.c1)
+            && self.c2.vartime_eq(&other.c2)
+    }
+
     fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
         if elems.len() != (Self::extension_degree() as usize) {
             return None;
@@ -225,6 +279,12 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         self
     }
 
+    fn conditional_negate(&mut self, flag: bool) {
+        self.c0.conditional_negate(flag);
+        self.c1.conditional_negate(flag);
+        self.c2.conditional_negate(flag);
+    }
+
     #[inline]
     fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         let split_at = bytes.len() / 3;
@@ -245,6 +305,18 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
     }
 
+    fn from_random_bytes_consumed(bytes: &[u8]) -> Option<(Self, usize)> {
+        // The coefficients are read back to back, so no byte is skipped.
+        let (c0, consumed0) = P::BaseField::from_random_bytes_consumed(bytes)?;
+        let bytes = &bytes[consumed0..];
+        let (c1, consumed1) = P::BaseField::from_random_bytes_consumed(bytes)?;
+        let (c2, consumed2) = P::BaseField::from_random_bytes_consumed(&bytes[consumed1..])?;
+        Some((
+            CubicExtField::new(c0, c1, c2),
+            consumed0 + consumed1 + consumed2,
+        ))
+    }
+
     fn square(&self) -> Self {
         let mut result = *self;
         result.square_in_place();
@@ -286,6 +358,11 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         self.norm().legendre()
     }
 
+    fn is_square(&self) -> bool {
+        // An element is a square iff its norm is a square in the base field.
+        self.norm().is_square()
+    }
+
     fn inverse(&self) -> Option<Self> {
         if self.is_zero() {
             None
@@ -319,6 +396,40 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         }
     }
 
+    fn inverse_zeroizing(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Same as `inverse`, but zeroizing the intermediate values.
+            let mut t0 = self.c0.square();
+            let mut t1 = self.c1.square();
+            let mut t2 = self.c2.square();
+            let mut t3 = self.c0 * &self.c1;
+            let mut t4 = self.c0 * &self.c2;
+            let mut t5 = self.c1 * &self.c2;
+            let mut n5 = P::mul_base_field_by_nonresidue(t5);
+
+            let mut s0 = t0 - &n5;
+            let mut s1 = P::mul_base_field_by_nonresidue(t2) - &t3;
+            let mut s2 = t1 - &t4;
+
+            let mut a1 = self.c2 * &s1;
+            let mut a2 = self.c1 * &s2;
+            let mut a3 = P::mul_base_field_by_nonresidue(a1 + &a2);
+            let mut norm = self.c0 * &s0 + &a3;
+            let mut t6 = norm.inverse_zeroizing().unwrap();
+
+            let result = Self::new(t6 * &s0, t6 * &s1, t6 * &s2);
+            for x in [
+                &mut t0, &mut t1, &mut t2, &mut t3, &mut t4, &mut t5, &mut t6, &mut n5, &mut s0,
+                &mut s1, &mut s2, &mut a1, &mut a2, &mut a3, &mut norm,
+            ] {
+                x.zeroize();
+            }
+            Some(result)
+        }
+    }
+
     fn inverse_in_place(&mut self) -> Option<&mut Self> {
         if let Some(inverse) = self.inverse() {
             *self = inverse;
@@ -329,6 +440,10 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
     }
 
     fn frobenius_map(&mut self, power: usize) {
+        let power = power % Self::extension_degree() as usize;
+        if power == 0 {
+            return;
+        }
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
         self.c2.frobenius_map(power);
@@ -373,6 +488,25 @@ impl<P: CubicExtConfig> Zeroize for CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtConfig> ConstantTimeEq for CubicExtField<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Field::ct_eq(self, other)
+    }
+}
+
+impl<P: CubicExtConfig> ConditionallySelectable for CubicExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
 impl<P: CubicExtConfig> From<u128> for CubicExtField<P> {
     fn from(other: u128) -> Self {
         let fe: P::BaseField = other.into();
@@ -705,6 +839,23 @@ mod cube_ext_tests {
         Field,
     };
 
+    #[test]
+    fn test_validate_canonical() {
+        use ark_test_curves::{PrimeField, UniformRand};
+
+        let mut rng = test_rng();
+        let a = Fq6::rand(&mut rng);
+        assert!(a.validate_canonical());
+
+        // Replace one coordinate deep in the tower with `p`, a non-canonical
+        // representation of zero.
+        let mut b = a;
+        b.c2.c1 = Fq::new_unchecked(Fq::MODULUS);
+        assert!(!b.validate_canonical());
+        b.c2.c1 = Fq::zero();
+        assert!(b.validate_canonical());
+    }
+
     #[test]
     fn test_norm_for_towers() {
         // First, test the simple fp3
@@ -766,4 +917,42 @@ mod cube_ext_tests {
             );
         }
     }
+
+    #[test]
+    fn test_validate_frobenius_coeffs() {
+        Fq3::validate_frobenius_coeffs();
+        Fq6::validate_frobenius_coeffs();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "wrong FROBENIUS_COEFF_C1[0]")]
+    fn test_validate_frobenius_coeffs_wrong_coeff() {
+        use ark_test_curves::{
+            mnt6_753::{Fq as MntFq, Fq3Config},
+            Fp3, Fp3Config,
+        };
+
+        // `Fq3Config` with the first two Frobenius coefficients swapped.
+        struct BadFq3Config;
+        impl Fp3Config for BadFq3Config {
+            type Fp = MntFq;
+            const NONRESIDUE: MntFq = Fq3Config::NONRESIDUE;
+            const FROBENIUS_COEFF_FP3_C1: &'static [MntFq] = &[
+                Fq3Config::FROBENIUS_COEFF_FP3_C1[1],
+                Fq3Config::FROBENIUS_COEFF_FP3_C1[0],
+                Fq3Config::FROBENIUS_COEFF_FP3_C1[2],
+            ];
+            const FROBENIUS_COEFF_FP3_C2: &'static [MntFq] = Fq3Config::FROBENIUS_COEFF_FP3_C2;
+            const TWO_ADICITY: u32 = Fq3Config::TWO_ADICITY;
+            const TRACE_MINUS_ONE_DIV_TWO: &'static [u64] = Fq3Config::TRACE_MINUS_ONE_DIV_TWO;
+            const QUADRATIC_NONRESIDUE_TO_T: Fp3<Self> = Fp3::new(
+                Fq3Config::QUADRATIC_NONRESIDUE_TO_T.c0,
+                Fq3Config::QUADRATIC_NONRESIDUE_TO_T.c1,
+                Fq3Config::QUADRATIC_NONRESIDUE_TO_T.c2,
+            );
+        }
+
+        Fp3::<BadFq3Config>::validate_frobenius_coeffs();
+    }
 }
@@ -18,7 +18,14 @@ use ark_std::{
 mod montgomery_backend;
 pub use montgomery_backend::*;
 
-use crate::{BigInt, BigInteger, FftField, Field, LegendreSymbol, PrimeField, SqrtPrecomputation};
+#[cfg(target_pointer_width = "64")]
+mod montgomery_backend_128;
+#[cfg(target_pointer_width = "64")]
+pub use montgomery_backend_128::*;
+
+use crate::{
+    BigInt, BigInteger, FftField, Field, FieldRepr, LegendreSymbol, PrimeField, SqrtPrecomputation,
+};
 /// A trait that specifies the configuration of a prime field.
 /// Also specifies how to perform arithmetic on field elements.
 pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
@@ -38,6 +45,9 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// such that, for all elements `f` of the field, `e * f = f`.
     const ONE: Fp<Self, N>;
 
+    /// The additive inverse of the multiplicative identity, i.e. `-1`.
+    const NEG_ONE: Fp<Self, N>;
+
     /// Let `N` be the size of the multiplicative group defined by the field.
     /// Then `TWO_ADICITY` is the two-adicity of `N`, i.e. the integer `s`
     /// such that `N = 2^s * t` for some odd integer `t`.
@@ -82,12 +92,41 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// Compute the inner product `<a, b>`.
     fn sum_of_products<const T: usize>(a: &[Fp<Self, N>; T], b: &[Fp<Self, N>; T]) -> Fp<Self, N>;
 
+    /// Compute the sum of the elements of `iter`.
+    fn sum_of_elements<I: Iterator<Item = Fp<Self, N>>>(iter: I) -> Fp<Self, N> {
+        iter.fold(Self::ZERO, |mut sum, x| {
+            Self::add_assign(&mut sum, &x);
+            sum
+        })
+    }
+
+    /// Compute the product of the elements of `iter`.
+    fn product_of_elements<I: Iterator<Item = Fp<Self, N>>>(iter: I) -> Fp<Self, N> {
+        iter.fold(Self::ONE, |mut product, x| {
+            Self::mul_assign(&mut product, &x);
+            product
+        })
+    }
+
     /// Set a *= b.
     fn square_in_place(a: &mut Fp<Self, N>);
 
     /// Compute a^{-1} if `a` is not zero.
     fn inverse(a: &Fp<Self, N>) -> Option<Fp<Self, N>>;
 
+    /// Returns the inverse of `Self::GENERATOR`.
+    fn generator_inv() -> Fp<Self, N> {
+        Self::inverse(&Self::GENERATOR).unwrap()
+    }
+
+    /// Compute a^{-1} if `a` is not zero, zeroizing the scratch values of the
+    /// computation before returning.
+    ///
+    /// The default falls back to `Self::inverse` and scrubs nothing.
+    fn inverse_zeroizing(a: &Fp<Self, N>) -> Option<Fp<Self, N>> {
+        Self::inverse(a)
+    }
+
     /// Construct a field element from an integer in the range
     /// `0..(Self::MODULUS - 1)`. Returns `None` if the integer is outside
     /// this range.
@@ -106,7 +145,6 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     Hash(bound = ""),
     Clone(bound = ""),
     Copy(bound = ""),
-    PartialEq(bound = ""),
     Eq(bound = "")
 )]
 pub struct Fp<P, const N: usize>(
@@ -185,6 +223,7 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
     const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = P::SQRT_PRECOMP;
     const ZERO: Self = P::ZERO;
     const ONE: Self = P::ONE;
+    const NEG_ONE: Self = P::NEG_ONE;
 
     fn extension_degree() -> u64 {
         1
@@ -198,6 +237,14 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         iter::once(*self)
     }
 
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(&(self.0).0[..], &(other.0).0[..])
+    }
+
+    fn vartime_eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
     fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
         if elems.len() != (Self::extension_degree() as usize) {
             return None;
@@ -224,6 +271,20 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         self
     }
 
+    #[inline]
+    fn conditional_negate(&mut self, flag: bool) {
+        // Compute `p - self` and mask it to zero if `self` is zero, so that
+        // computing the negation does not branch on `self` either.
+        let mut neg = P::MODULUS;
+        neg.sub_with_borrow(&self.0);
+        let nonzero = self.0 .0.iter().fold(0u64, |acc, limb| acc | limb);
+        let nonzero_mask = 0u64.wrapping_sub((nonzero | nonzero.wrapping_neg()) >> 63);
+        let flag_mask = 0u64.wrapping_sub(u64::from(flag));
+        for (a, b) in self.0 .0.iter_mut().zip(neg.0) {
+            *a ^= (*a ^ (b & nonzero_mask)) & flag_mask;
+        }
+    }
+
     #[inline]
     fn characteristic() -> &'static [u64] {
         P::MODULUS.as_ref()
@@ -234,6 +295,36 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         P::sum_of_products(a, b)
     }
 
+    /// Buffers the products in fixed-size chunks so that each chunk is
+    /// computed by [`FpConfig::sum_of_products`] with delayed reduction.
+    fn sum_of_products_iter<I, J>(a: I, b: J) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+        J: IntoIterator<Item = Self>,
+    {
+        const CHUNK_SIZE: usize = 16;
+        let mut pairs = a.into_iter().zip(b);
+        let mut sum = Self::zero();
+        loop {
+            let mut a_chunk = [Self::zero(); CHUNK_SIZE];
+            let mut b_chunk = [Self::zero(); CHUNK_SIZE];
+            let mut len = 0;
+            for (a, b) in pairs.by_ref().take(CHUNK_SIZE) {
+                a_chunk[len] = a;
+                b_chunk[len] = b;
+                len += 1;
+            }
+            if len == 0 {
+                break;
+            }
+            sum += P::sum_of_products(&a_chunk, &b_chunk);
+            if len < CHUNK_SIZE {
+                break;
+            }
+        }
+        sum
+    }
+
     #[inline]
     fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
         if F::BIT_SIZE > 8 {
@@ -280,6 +371,13 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         }
     }
 
+    fn from_random_bytes_consumed(bytes: &[u8]) -> Option<(Self, usize)> {
+        // Only the bytes covering `MODULUS_BIT_SIZE` bits (and no flags) are
+        // interpreted.
+        let consumed = buffer_byte_size(Self::MODULUS_BIT_SIZE as usize + EmptyFlags::BIT_SIZE);
+        Self::from_random_bytes(bytes).map(|f| (f, consumed.min(bytes.len())))
+    }
+
     #[inline]
     fn square(&self) -> Self {
         let mut temp = *self;
@@ -297,6 +395,11 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         P::inverse(self)
     }
 
+    #[inline]
+    fn inverse_zeroizing(&self) -> Option<Self> {
+        P::inverse_zeroizing(self)
+    }
+
     fn inverse_in_place(&mut self) -> Option<&mut Self> {
         if let Some(inverse) = self.inverse() {
             *self = inverse;
@@ -329,6 +432,7 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
 impl<P: FpConfig<N>, const N: usize> PrimeField for Fp<P, N> {
     type BigInt = BigInt<N>;
     const MODULUS: Self::BigInt = P::MODULUS;
+    const MODULUS_MINUS_ONE: Self::BigInt = P::MODULUS.const_sub_with_borrow(&BigInt::one()).0;
     const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = P::MODULUS.divide_by_2_round_down();
     const MODULUS_BIT_SIZE: u32 = P::MODULUS.const_num_bits();
     const TRACE: Self::BigInt = P::MODULUS.two_adic_coefficient();
@@ -342,6 +446,10 @@ impl<P: FpConfig<N>, const N: usize> PrimeField for Fp<P, N> {
     fn into_bigint(self) -> BigInt<N> {
         P::into_bigint(self)
     }
+
+    fn debug_repr(&self) -> FieldRepr {
+        FieldRepr::new(self.0.as_ref(), self.into_bigint())
+    }
 }
 
 impl<P: FpConfig<N>, const N: usize> FftField for Fp<P, N> {
@@ -351,6 +459,11 @@ impl<P: FpConfig<N>, const N: usize> FftField for Fp<P, N> {
     const SMALL_SUBGROUP_BASE: Option<u32> = P::SMALL_SUBGROUP_BASE;
     const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = P::SMALL_SUBGROUP_BASE_ADICITY;
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self> = P::LARGE_SUBGROUP_ROOT_OF_UNITY;
+
+    #[inline]
+    fn generator_inv() -> Self {
+        P::generator_inv()
+    }
 }
 
 /// Note that this implementation of `Ord` compares field elements viewing
@@ -499,6 +612,27 @@ impl<P: FpConfig<N>, const N: usize> From<i8> for Fp<P, N> {
     }
 }
 
+/// Interprets `bytes` as the little-endian encoding of a canonical
+/// representative, as written by [`CanonicalSerialize`].
+///
+/// Fails with [`SerializationError::InvalidData`] if `bytes` is not exactly
+/// [`CanonicalSerialize::compressed_size`] long, or if it encodes a value that
+/// is not smaller than the modulus.
+impl<P: FpConfig<N>, const N: usize> TryFrom<&[u8]> for Fp<P, N> {
+    type Error = SerializationError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != buffer_byte_size(Self::MODULUS_BIT_SIZE as usize) {
+            return Err(SerializationError::InvalidData);
+        }
+        let mut repr = BigInt::<N>::zero();
+        for (i, byte) in bytes.iter().enumerate() {
+            repr.0[i / 8] |= u64::from(*byte) << (8 * (i % 8));
+        }
+        Self::from_bigint(repr).ok_or(SerializationError::InvalidData)
+    }
+}
+
 impl<P: FpConfig<N>, const N: usize> ark_std::rand::distributions::Distribution<Fp<P, N>>
     for ark_std::rand::distributions::Standard
 {
@@ -795,14 +929,14 @@ impl<'a, P: FpConfig<N>, const N: usize> core::ops::Sub<&'a mut Self> for Fp<P,
 #[allow(unused_qualifications)]
 impl<P: FpConfig<N>, const N: usize> core::iter::Sum<Self> for Fp<P, N> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Self::zero(), core::ops::Add::add)
+        P::sum_of_elements(iter)
     }
 }
 
 #[allow(unused_qualifications)]
 impl<'a, P: FpConfig<N>, const N: usize> core::iter::Sum<&'a Self> for Fp<P, N> {
     fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-        iter.fold(Self::zero(), core::ops::Add::add)
+        P::sum_of_elements(iter.copied())
     }
 }
 
@@ -900,14 +1034,14 @@ impl<'a, P: FpConfig<N>, const N: usize> core::ops::Div<&'a mut Self> for Fp<P,
 #[allow(unused_qualifications)]
 impl<P: FpConfig<N>, const N: usize> core::iter::Product<Self> for Fp<P, N> {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Self::one(), core::ops::Mul::mul)
+        P::product_of_elements(iter)
     }
 }
 
 #[allow(unused_qualifications)]
 impl<'a, P: FpConfig<N>, const N: usize> core::iter::Product<&'a Self> for Fp<P, N> {
     fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
-        iter.fold(Self::one(), Mul::mul)
+        P::product_of_elements(iter.copied())
     }
 }
 
@@ -951,6 +1085,26 @@ impl<P: FpConfig<N>, const N: usize> zeroize::Zeroize for Fp<P, N> {
     }
 }
 
+/// Compares every limb of the two representations, without returning early on
+/// a mismatch. See [`Field::vartime_eq`] for a comparison that may.
+impl<P, const N: usize> PartialEq for Fp<P, N> {
+    fn eq(&self, other: &Self) -> bool {
+        subtle::ConstantTimeEq::ct_eq(&(self.0).0[..], &(other.0).0[..]).into()
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> subtle::ConstantTimeEq for Fp<P, N> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        Field::ct_eq(self, other)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> subtle::ConditionallySelectable for Fp<P, N> {
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        Fp(BigInt::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
 impl<P: FpConfig<N>, const N: usize> From<num_bigint::BigUint> for Fp<P, N> {
     #[inline]
     fn from(val: num_bigint::BigUint) -> Fp<P, N> {
@@ -979,3 +1133,218 @@ impl<P: FpConfig<N>, const N: usize> From<BigInt<N>> for Fp<P, N> {
         Self::from_bigint(int).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Fp64, MontBackend, MontConfig};
+    use crate::{fields::tower_pow, BigInt, FftField, Field, PrimeField, SqrtPrecomputation};
+    use ark_std::{test_rng, vec, vec::Vec, UniformRand, Zero};
+    use num_bigint::BigUint;
+
+    // The BabyBear prime `15 * 2^27 + 1`.
+    struct BabyBearConfig;
+    impl MontConfig<1> for BabyBearConfig {
+        const MODULUS: BigInt<1> = BigInt([2013265921]);
+        const GENERATOR: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([31]));
+        const TWO_ADIC_ROOT_OF_UNITY: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([440564289]));
+    }
+    type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+    // The largest prime below `2^32`, so that sums of residues overflow `u32`.
+    struct F4294967291Config;
+    impl MontConfig<1> for F4294967291Config {
+        const MODULUS: BigInt<1> = BigInt([4294967291]);
+        const GENERATOR: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([2]));
+        const TWO_ADIC_ROOT_OF_UNITY: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([4294967290]));
+    }
+    type F4294967291 = Fp64<MontBackend<F4294967291Config, 1>>;
+
+    // The Mersenne prime `2^61 - 1`.
+    struct M61Config;
+    impl MontConfig<1> for M61Config {
+        const MODULUS: BigInt<1> = BigInt([2305843009213693951]);
+        const GENERATOR: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([37]));
+        const TWO_ADIC_ROOT_OF_UNITY: Fp64<MontBackend<Self, 1>> =
+            Fp64::new(BigInt([2305843009213693950]));
+    }
+    type M61 = Fp64<MontBackend<M61Config, 1>>;
+
+    // `3 * 2^41 + 1`, whose two-adicity exceeds the Cipolla threshold.
+    struct F6597069766657Config;
+    impl MontConfig<1> for F6597069766657Config {
+        const MODULUS: BigInt<1> = BigInt([6597069766657]);
+        const GENERATOR: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([5]));
+        const TWO_ADIC_ROOT_OF_UNITY: Fp64<MontBackend<Self, 1>> = Fp64::new(BigInt([125]));
+    }
+    type F6597069766657 = Fp64<MontBackend<F6597069766657Config, 1>>;
+
+    fn check_sum<F: PrimeField>() {
+        let mut rng = test_rng();
+        let v: Vec<F> = (0..10_000).map(|_| F::rand(&mut rng)).collect();
+        let expected = v.iter().fold(F::zero(), |sum, x| sum + x);
+        assert_eq!(v.iter().sum::<F>(), expected);
+
+        let minus_one = [-F::one(); 10_000];
+        assert_eq!(minus_one.iter().sum::<F>(), -F::from(10_000u64));
+    }
+
+    #[test]
+    fn test_sum() {
+        check_sum::<BabyBear>();
+        check_sum::<F4294967291>();
+        check_sum::<M61>();
+        #[cfg(target_pointer_width = "64")]
+        check_sum::<Fp64<super::MontBackend128<F4294967291Config, 1>>>();
+    }
+
+    fn check_product<F: Field>() {
+        let mut rng = test_rng();
+        let v: Vec<F> = (0..10_000).map(|_| F::rand(&mut rng)).collect();
+        let expected = v.iter().fold(F::one(), |product, x| product * x);
+        assert_eq!(v.iter().product::<F>(), expected);
+        assert_eq!(v.into_iter().product::<F>(), expected);
+
+        let minus_one = [-F::one(); 10_001];
+        assert_eq!(minus_one.iter().product::<F>(), -F::one());
+        assert_eq!(core::iter::empty::<F>().product::<F>(), F::one());
+    }
+
+    #[test]
+    fn test_product() {
+        check_product::<BabyBear>();
+        check_product::<F4294967291>();
+        check_product::<M61>();
+        #[cfg(target_pointer_width = "64")]
+        check_product::<Fp64<super::MontBackend128<F4294967291Config, 1>>>();
+    }
+
+    #[test]
+    fn test_cipolla_sqrt() {
+        type F = F6597069766657;
+        // The two-adicity exceeds `CIPOLLA_TWO_ADICITY_THRESHOLD`.
+        assert_eq!(F::TWO_ADICITY, 41);
+        assert!(matches!(
+            F::SQRT_PRECOMP,
+            Some(SqrtPrecomputation::Cipolla { .. })
+        ));
+
+        // `(t - 1) / 2 = 1` for the trace `t = 3`.
+        let tonelli_shanks = SqrtPrecomputation::TonelliShanks {
+            two_adicity: 41,
+            quadratic_nonresidue_to_trace: F::TWO_ADIC_ROOT_OF_UNITY,
+            trace_of_modulus_minus_one_div_two: &[1],
+        };
+        let mut rng = test_rng();
+        assert_eq!(F::zero().sqrt(), Some(F::zero()));
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let square = a.square();
+            let root = square.sqrt().unwrap();
+            let expected = tonelli_shanks.sqrt(&square).unwrap();
+            assert!(root == expected || root == -expected);
+            assert!(root == a || root == -a);
+
+            let non_square = square * F::GENERATOR;
+            assert_eq!(tonelli_shanks.sqrt(&non_square), None);
+            assert_eq!(non_square.sqrt(), None);
+        }
+    }
+
+    #[test]
+    fn test_is_primitive_element() {
+        // 2013265920 = 2^27 * 3 * 5
+        let factorization = [(2, 27), (3, 1), (5, 1)];
+        assert!(BabyBear::from(31u8).is_primitive_element(&factorization));
+        assert!(!BabyBear::from(0u8).is_primitive_element(&factorization));
+        assert!(!BabyBear::from(1u8).is_primitive_element(&factorization));
+        // Squares, cubes and fifth powers are not primitive.
+        for k in [2u8, 3, 5, 6, 30] {
+            let a = BabyBear::from(31u8).pow([u64::from(k)]);
+            assert!(!a.is_primitive_element(&factorization));
+        }
+        // Powers coprime to `p - 1` are.
+        for k in [7u8, 11, 13, 77] {
+            let a = BabyBear::from(31u8).pow([u64::from(k)]);
+            assert!(a.is_primitive_element(&factorization));
+        }
+    }
+
+    #[test]
+    fn test_is_valid_extension_nonresidue() {
+        // BabyBear's quartic extension is defined by `x^4 - 11`.
+        assert!(BabyBear::from(11u8).is_valid_extension_nonresidue(1, 4));
+        // Every element of the prime field is a square in its quadratic
+        // extension.
+        assert!(!BabyBear::from(11u8).is_valid_extension_nonresidue(2, 2));
+        // `p = 1 mod 4`, so `-1` is a square, and `4` is a square.
+        assert!(!(-BabyBear::from(1u8)).is_valid_extension_nonresidue(1, 2));
+        assert!(!BabyBear::from(4u8).is_valid_extension_nonresidue(1, 4));
+        assert!(!BabyBear::from(0u8).is_valid_extension_nonresidue(1, 2));
+        assert!(BabyBear::from(0u8).is_valid_extension_nonresidue(1, 1));
+        // `7` does not divide `p^k - 1` for `k < 3`.
+        assert!(!BabyBear::from(31u8).is_valid_extension_nonresidue(1, 7));
+        // `p = 3 mod 4`, so `-1` is not a square, but `x^4 + 1` is reducible.
+        assert!((-M61::from(1u8)).is_valid_extension_nonresidue(1, 2));
+        assert!(!(-M61::from(1u8)).is_valid_extension_nonresidue(1, 4));
+    }
+
+    #[test]
+    fn test_short_fingerprint_small_modulus() {
+        // Encodings of at most four bytes are returned in full.
+        assert_eq!(BabyBear::from(1u8).short_fingerprint(), "00000001");
+        assert_eq!(F4294967291::from(0xabcdu32).short_fingerprint(), "0000abcd");
+        assert_eq!(M61::from(1u8).short_fingerprint(), "0000…0001");
+    }
+
+    #[test]
+    fn test_build_tower_nonresidues() {
+        fn check<F: PrimeField>(degrees: &[u32]) -> Vec<Vec<F>> {
+            let nonresidues = F::build_tower_nonresidues(degrees).unwrap();
+            assert_eq!(nonresidues.len(), degrees.len());
+            let modulus: BigUint = F::MODULUS.into();
+            let mut levels = Vec::new();
+            let mut base_degree = 1;
+            for (nonresidue, &degree) in nonresidues.iter().zip(degrees) {
+                assert_eq!(nonresidue.len(), base_degree as usize);
+                let mut one = vec![F::zero(); nonresidue.len()];
+                one[0] = F::one();
+                // The non-residue is not an `r`-th power in the level below
+                // for any prime `r` dividing the degree.
+                let q_minus_one = modulus.pow(base_degree) - 1u8;
+                for r in [2u32, 3, 5].iter().filter(|&r| degree % r == 0) {
+                    assert!((&q_minus_one % r).is_zero());
+                    let power = tower_pow(&levels, nonresidue, &(&q_minus_one / r));
+                    assert_ne!(power, one, "level {}, r = {}", levels.len(), r);
+                }
+                levels.push((degree, nonresidue.clone()));
+                base_degree *= degree;
+            }
+            nonresidues
+        }
+        check::<BabyBear>(&[2, 3]);
+        check::<BabyBear>(&[4]);
+        check::<BabyBear>(&[3, 5]);
+        check::<M61>(&[2, 3]);
+        check::<M61>(&[]);
+        assert_eq!(
+            M61::build_tower_nonresidues(&[2]),
+            Some(vec![vec![-M61::from(1u8)]])
+        );
+
+        // Every element of the prime field is a square in an even-degree
+        // extension, so these levels need a non-residue outside of it.
+        let nonresidues = check::<BabyBear>(&[2, 2]);
+        assert!(!nonresidues[1][1].is_zero());
+        // `Fp -> Fp2 -> Fp6 -> Fp12`.
+        let nonresidues = check::<BabyBear>(&[2, 3, 2]);
+        assert!(nonresidues[2][1..].iter().any(|c| !c.is_zero()));
+        let nonresidues = check::<M61>(&[2, 3, 2]);
+        assert!(nonresidues[2][1..].iter().any(|c| !c.is_zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the factorization does not multiply to `p - 1`")]
+    fn test_is_primitive_element_wrong_factorization() {
+        BabyBear::from(31u8).is_primitive_element(&[(2, 27), (3, 1)]);
+    }
+}
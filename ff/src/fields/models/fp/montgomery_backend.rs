@@ -1,8 +1,12 @@
 use ark_std::{marker::PhantomData, Zero};
 
 use super::{Fp, FpConfig};
-use crate::{biginteger::arithmetic as fa, BigInt, BigInteger, PrimeField, SqrtPrecomputation};
+use crate::{
+    biginteger::arithmetic as fa, BigInt, BigInteger, PrimeField, SqrtPrecomputation,
+    CIPOLLA_TWO_ADICITY_THRESHOLD,
+};
 use ark_ff_macros::unroll_for_loops;
+use zeroize::Zeroize;
 
 /// A trait that specifies the constants and arithmetic procedures
 /// for Montgomery arithmetic over the prime field defined by `MODULUS`.
@@ -22,14 +26,32 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     /// R2 = R^2 % Self::MODULUS
     const R2: BigInt<N> = Self::MODULUS.montgomery_r2();
 
+    /// MONTGOMERY_R_INV = R^{-1} % Self::MODULUS, so that the canonical
+    /// representative of an element is its Montgomery form times
+    /// `MONTGOMERY_R_INV`, modulo
+    /// `Self::MODULUS`.
+    // A Montgomery multiplication of `1` by `1` yields `1 * 1 * R^{-1}`.
+    const MONTGOMERY_R_INV: BigInt<N> =
+        Fp::<MontBackend<Self, N>, N>::new_unchecked(BigInt::<N>::one())
+            .mul(&Fp::new_unchecked(BigInt::<N>::one()))
+            .0;
+
     /// INV = -MODULUS^{-1} mod 2^64
     const INV: u64 = inv::<Self, N>();
 
+    /// BARRETT_MU = floor(2^(2k) / Self::MODULUS), where `k` is the bit
+    /// length of `Self::MODULUS`, for Barrett reduction in
+    /// [`Fp::reduce_wide`].
+    const BARRETT_MU: BigInt<N> = Self::MODULUS.barrett_mu();
+
     /// A multiplicative generator of the field.
     /// `Self::GENERATOR` is an element having multiplicative order
     /// `Self::MODULUS - 1`.
     const GENERATOR: Fp<MontBackend<Self, N>, N>;
 
+    /// The inverse of `Self::GENERATOR`, computed at compile time.
+    const GENERATOR_INV: Fp<MontBackend<Self, N>, N> = Self::GENERATOR.const_inverse();
+
     /// Can we use the no-carry optimization for multiplication
     /// outlined [here](https://hackmd.io/@gnark/modular_multiplication)?
     ///
@@ -86,6 +108,16 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
         }
     };
 
+    /// (MODULUS + 1) / 2. Used for square root precomputations.
+    #[doc(hidden)]
+    const MODULUS_PLUS_ONE_DIV_TWO: BigInt<N> = {
+        let (modulus_plus_one, carry) = Self::MODULUS.const_add_with_carry(&BigInt::<N>::one());
+        let mut result = modulus_plus_one.divide_by_2_round_down();
+        // Set the MSB to `carry` to get the correct result of (MODULUS + 1) // 2.
+        result.0[N - 1] |= (carry as u64) << 63;
+        result
+    };
+
     /// Sets `a = a + b`.
     #[inline(always)]
     fn add_assign(a: &mut Fp<MontBackend<Self, N>, N>, b: &Fp<MontBackend<Self, N>, N>) {
@@ -270,59 +302,13 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
     }
 
     fn inverse(a: &Fp<MontBackend<Self, N>, N>) -> Option<Fp<MontBackend<Self, N>, N>> {
-        if a.is_zero() {
-            None
-        } else {
-            // Guajardo Kumar Paar Pelzl
-            // Efficient Software-Implementation of Finite Fields with Applications to
-            // Cryptography
-            // Algorithm 16 (BEA for Inversion in Fp)
-
-            let one = BigInt::from(1u64);
-
-            let mut u = a.0;
-            let mut v = Self::MODULUS;
-            let mut b = Fp::new_unchecked(Self::R2); // Avoids unnecessary reduction step.
-            let mut c = Fp::zero();
-
-            while u != one && v != one {
-                while u.is_even() {
-                    u.div2();
-
-                    if b.0.is_even() {
-                        b.0.div2();
-                    } else {
-                        b.0.add_with_carry(&Self::MODULUS);
-                        b.0.div2();
-                    }
-                }
-
-                while v.is_even() {
-                    v.div2();
-
-                    if c.0.is_even() {
-                        c.0.div2();
-                    } else {
-                        c.0.add_with_carry(&Self::MODULUS);
-                        c.0.div2();
-                    }
-                }
-
-                if v < u {
-                    u.sub_with_borrow(&v);
-                    b -= &c;
-                } else {
-                    v.sub_with_borrow(&u);
-                    c -= &b;
-                }
-            }
+        binary_euclidean_inverse(a, false)
+    }
 
-            if u == one {
-                Some(b)
-            } else {
-                Some(c)
-            }
-        }
+    /// Like [`Self::inverse`], but zeroizes the scratch values of the binary
+    /// extended Euclidean algorithm before returning.
+    fn inverse_zeroizing(a: &Fp<MontBackend<Self, N>, N>) -> Option<Fp<MontBackend<Self, N>, N>> {
+        binary_euclidean_inverse(a, true)
     }
 
     fn from_bigint(r: BigInt<N>) -> Option<Fp<MontBackend<Self, N>, N>> {
@@ -462,6 +448,142 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
                 .sum()
         }
     }
+
+    /// Computes the sum of the elements of `iter`, reducing only once at the
+    /// end instead of after every addition.
+    fn sum_of_elements<I: Iterator<Item = Fp<MontBackend<Self, N>, N>>>(
+        iter: I,
+    ) -> Fp<MontBackend<Self, N>, N> {
+        // Montgomery form is linear, so the representations can be summed as
+        // plain integers. `lo` holds the low `N` limbs of that sum, and `hi`
+        // counts the carries out of them.
+        let mut sum = Fp::zero();
+        let mut lo = BigInt::<N>::zero();
+        let mut hi = 0u64;
+        for x in iter {
+            hi += lo.add_with_carry(&x.0) as u64;
+            if hi == u64::MAX {
+                sum += Self::reduce_wide_sum(lo, hi);
+                lo = BigInt::zero();
+                hi = 0;
+            }
+        }
+        sum + Self::reduce_wide_sum(lo, hi)
+    }
+
+    /// Computes the product of the elements of `iter`, subtracting the modulus
+    /// only once at the end instead of after every multiplication.
+    fn product_of_elements<I: Iterator<Item = Fp<MontBackend<Self, N>, N>>>(
+        iter: I,
+    ) -> Fp<MontBackend<Self, N>, N> {
+        if !Self::CAN_USE_NO_CARRY_MUL_OPT {
+            return iter.fold(Fp::new_unchecked(Self::R), |product, x| product * x);
+        }
+        // Since `4 * MODULUS < R`, the Montgomery product of two values in
+        // `[0, 2 * MODULUS)` stays in `[0, 2 * MODULUS)` without its final
+        // conditional subtraction.
+        let mut product = Fp::new_unchecked(Self::R);
+        for x in iter {
+            product = product.mul_without_cond_subtract(&x);
+        }
+        product.subtract_modulus();
+        product
+    }
+
+    /// Returns the element whose Montgomery representation is congruent to
+    /// `hi * 2^(64 * N) + lo`.
+    #[doc(hidden)]
+    fn reduce_wide_sum(lo: BigInt<N>, hi: u64) -> Fp<MontBackend<Self, N>, N> {
+        // Montgomery reduction maps `lo` to `lo * R^{-1}` in `[0, MODULUS]`.
+        let mut lo = Self::into_bigint(Fp::new_unchecked(lo));
+        if lo == Self::MODULUS {
+            lo = BigInt::zero();
+        }
+        // Since `R = 2^(64 * N)`, the high part represents `hi` itself.
+        Self::from_bigint(lo).unwrap() + Fp::from(hi)
+    }
+}
+
+/// Returns the low `N` limbs of `(hi * 2^(64N) + lo) >> shift`.
+fn shr_wide<const N: usize>(lo: &[u64; N], hi: &[u64; N], shift: usize) -> [u64; N] {
+    let limb = |i: usize| match i {
+        i if i < N => lo[i],
+        i if i < 2 * N => hi[i - N],
+        _ => 0,
+    };
+    let (limbs, bits) = (shift / 64, shift % 64);
+    let mut result = [0u64; N];
+    for (i, r) in result.iter_mut().enumerate() {
+        *r = limb(i + limbs) >> bits;
+        if bits != 0 {
+            *r |= limb(i + limbs + 1) << (64 - bits);
+        }
+    }
+    result
+}
+
+/// Computes `a^{-1}` if `a` is not zero, zeroizing the scratch values
+/// afterwards if `zeroize_scratch` is set.
+fn binary_euclidean_inverse<T: MontConfig<N>, const N: usize>(
+    a: &Fp<MontBackend<T, N>, N>,
+    zeroize_scratch: bool,
+) -> Option<Fp<MontBackend<T, N>, N>> {
+    if a.is_zero() {
+        None
+    } else {
+        // Guajardo Kumar Paar Pelzl
+        // Efficient Software-Implementation of Finite Fields with Applications to
+        // Cryptography
+        // Algorithm 16 (BEA for Inversion in Fp)
+
+        let one = BigInt::from(1u64);
+
+        let mut u = a.0;
+        let mut v = T::MODULUS;
+        let mut b = Fp::new_unchecked(T::R2); // Avoids unnecessary reduction step.
+        let mut c = Fp::zero();
+
+        while u != one && v != one {
+            while u.is_even() {
+                u.div2();
+
+                if b.0.is_even() {
+                    b.0.div2();
+                } else {
+                    b.0.add_with_carry(&T::MODULUS);
+                    b.0.div2();
+                }
+            }
+
+            while v.is_even() {
+                v.div2();
+
+                if c.0.is_even() {
+                    c.0.div2();
+                } else {
+                    c.0.add_with_carry(&T::MODULUS);
+                    c.0.div2();
+                }
+            }
+
+            if v < u {
+                u.sub_with_borrow(&v);
+                b -= &c;
+            } else {
+                v.sub_with_borrow(&u);
+                c -= &b;
+            }
+        }
+
+        let result = if u == one { b } else { c };
+        if zeroize_scratch {
+            u.zeroize();
+            v.zeroize();
+            b.zeroize();
+            c.zeroize();
+        }
+        Some(result)
+    }
 }
 
 /// Compute -M^{-1} mod 2^64.
@@ -516,6 +638,11 @@ pub const fn sqrt_precomputation<const N: usize, T: MontConfig<N>>(
             }),
             None => None,
         },
+        _ if <MontBackend<T, N>>::TWO_ADICITY > CIPOLLA_TWO_ADICITY_THRESHOLD => {
+            Some(SqrtPrecomputation::Cipolla {
+                field_order_plus_one_div_two: &T::MODULUS_PLUS_ONE_DIV_TWO.0,
+            })
+        },
         _ => Some(SqrtPrecomputation::TonelliShanks {
             two_adicity: <MontBackend<T, N>>::TWO_ADICITY,
             quadratic_nonresidue_to_trace: T::TWO_ADIC_ROOT_OF_UNITY,
@@ -561,8 +688,57 @@ macro_rules! MontFp {
     }};
 }
 
+/// Construct an element of the Montgomery-backed prime field `$field` from a
+/// decimal or `0x`-prefixed hexadecimal string literal, optionally preceded by
+/// a minus sign.
+///
+/// Unlike [`MontFp!`], the field type is named explicitly, and the magnitude
+/// of the literal must be smaller than the modulus of the field rather than
+/// merely fit in its limbs.
+///
+/// # Panics
+///
+/// If the magnitude of the literal is not smaller than the modulus, this
+/// macro results in a
+/// * compile-time error if used in a const context
+/// * run-time error otherwise.
+///
+/// # Usage
+///
+/// ```rust
+/// # use ark_test_curves::{field, One};
+/// # use ark_test_curves::bls12_381 as ark_bls12_381;
+/// use ark_bls12_381::Fr;
+/// const LARGE: Fr = field!(Fr, "123456789012345678901234567890");
+/// const HEX: Fr = field!(Fr, "0x18ee90ff6c373e0ee4e3f0ad2");
+/// const NEG_ONE: Fr = field!(Fr, "-1");
+///
+/// assert_eq!(LARGE, HEX);
+/// assert_eq!(NEG_ONE, -Fr::one());
+/// ```
+///
+/// A literal that is not reduced modulo the field's modulus is rejected:
+///
+/// ```compile_fail
+/// # use ark_test_curves::field;
+/// # use ark_test_curves::bls12_381 as ark_bls12_381;
+/// use ark_bls12_381::Fr;
+/// const MODULUS: Fr = field!(
+///     Fr,
+///     "52435875175126190479447740508185965837690552500527637822603658699938581184513"
+/// );
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($field:ty, $c0:expr) => {{
+        let (is_positive, limbs) = $crate::ark_ff_macros::to_sign_and_limbs!($c0);
+        <$field>::from_sign_and_reduced_limbs(is_positive, &limbs)
+    }};
+}
+
 pub use ark_ff_macros::MontConfig;
 
+pub use field;
 pub use MontFp;
 
 pub struct MontBackend<T: MontConfig<N>, const N: usize>(PhantomData<T>);
@@ -584,6 +760,9 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
     /// such that, for all elements `f` of the field, `e * f = f`.
     const ONE: Fp<Self, N> = Fp::new_unchecked(T::R);
 
+    /// The additive inverse of the multiplicative identity, i.e. `-1`.
+    const NEG_ONE: Fp<Self, N> = Fp::new_unchecked(T::R).const_neg();
+
     const TWO_ADICITY: u32 = Self::MODULUS.two_adic_valuation();
     const TWO_ADIC_ROOT_OF_UNITY: Fp<Self, N> = T::TWO_ADIC_ROOT_OF_UNITY;
     const SMALL_SUBGROUP_BASE: Option<u32> = T::SMALL_SUBGROUP_BASE;
@@ -622,6 +801,14 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
         T::sum_of_products(a, b)
     }
 
+    fn sum_of_elements<I: Iterator<Item = Fp<Self, N>>>(iter: I) -> Fp<Self, N> {
+        T::sum_of_elements(iter)
+    }
+
+    fn product_of_elements<I: Iterator<Item = Fp<Self, N>>>(iter: I) -> Fp<Self, N> {
+        T::product_of_elements(iter)
+    }
+
     #[inline]
     #[allow(unused_braces, clippy::absurd_extreme_comparisons)]
     fn square_in_place(a: &mut Fp<Self, N>) {
@@ -632,6 +819,15 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
         T::inverse(a)
     }
 
+    #[inline]
+    fn generator_inv() -> Fp<Self, N> {
+        T::GENERATOR_INV
+    }
+
+    fn inverse_zeroizing(a: &Fp<Self, N>) -> Option<Fp<Self, N>> {
+        T::inverse_zeroizing(a)
+    }
+
     fn from_bigint(r: BigInt<N>) -> Option<Fp<Self, N>> {
         T::from_bigint(r)
     }
@@ -648,8 +844,55 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
     pub const R: BigInt<N> = T::R;
     #[doc(hidden)]
     pub const R2: BigInt<N> = T::R2;
+    /// The inverse of the Montgomery radix `R`, modulo `T::MODULUS`.
+    pub const MONTGOMERY_R_INV: BigInt<N> = T::MONTGOMERY_R_INV;
     #[doc(hidden)]
     pub const INV: u64 = T::INV;
+    #[doc(hidden)]
+    pub const BARRETT_MU: BigInt<N> = T::BARRETT_MU;
+
+    /// Reduces the little-endian integer `wide` modulo `T::MODULUS` with
+    /// Barrett reduction, which needs two multiprecision multiplications and
+    /// at most two conditional subtractions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wide` is not smaller than `2^(2k)`, where `k` is the bit
+    /// length of the modulus. This holds for instance for the product of two
+    /// integers smaller than the modulus.
+    pub fn reduce_wide(wide: &[u64]) -> Self {
+        let k = T::MODULUS.const_num_bits() as usize;
+        assert!(
+            crate::significant_bits(wide) <= 2 * k,
+            "`wide` must be smaller than 2^{}",
+            2 * k
+        );
+        let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
+        for (i, limb) in wide.iter().enumerate().filter(|(_, limb)| **limb != 0) {
+            if i < N {
+                lo[i] = *limb;
+            } else {
+                hi[i - N] = *limb;
+            }
+        }
+
+        // q = floor(floor(x / 2^(k - 1)) * mu / 2^(k + 1)) underestimates
+        // floor(x / p) by at most 2.
+        let q = shr_wide(&lo, &hi, k - 1);
+        let (q_mu_lo, q_mu_hi) = fa::mul_limbs_wide(&q, &T::BARRETT_MU.0);
+        let q = shr_wide(&q_mu_lo, &q_mu_hi, k + 1);
+
+        // r = x - q * p, which is smaller than 3p.
+        let (qp_lo, qp_hi) = fa::mul_limbs_wide(&q, &T::MODULUS.0);
+        let (mut r, borrow) = fa::sub_limbs_with_borrow(&lo, &qp_lo);
+        let mut r_hi = fa::sub_limbs_with_borrow(&hi, &qp_hi).0[0].wrapping_sub(borrow as u64);
+        while r_hi != 0 || BigInt(r) >= T::MODULUS {
+            let (diff, borrow) = fa::sub_limbs_with_borrow(&r, &T::MODULUS.0);
+            r = diff;
+            r_hi = r_hi.wrapping_sub(borrow as u64);
+        }
+        Self::from_bigint(BigInt(r)).unwrap()
+    }
 
     /// Construct a new field element from its underlying
     /// [`struct@BigInt`] data type.
@@ -664,6 +907,13 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         }
     }
 
+    /// Returns the limbs of the Montgomery form `self * R` of this element,
+    /// least significant first.
+    #[inline]
+    pub fn as_montgomery_limbs(&self) -> &[u64] {
+        self.0.as_ref()
+    }
+
     /// Construct a new field element from its underlying
     /// [`struct@BigInt`] data type.
     ///
@@ -707,6 +957,24 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         }
     }
 
+    /// Like [`Self::from_sign_and_limbs`], but additionally requires the
+    /// magnitude to be smaller than the modulus.
+    /// For *internal* use only; please use the `ark_ff::field` macro instead
+    /// of this method
+    #[doc(hidden)]
+    pub const fn from_sign_and_reduced_limbs(is_positive: bool, limbs: &[u64]) -> Self {
+        let mut repr = BigInt::<N>([0; N]);
+        assert!(limbs.len() <= N);
+        crate::const_for!((i in 0..(limbs.len())) {
+            repr.0[i] = limbs[i];
+        });
+        assert!(
+            !repr.const_geq(&T::MODULUS),
+            "value is not smaller than the modulus"
+        );
+        Self::from_sign_and_limbs(is_positive, limbs)
+    }
+
     const fn mul_without_cond_subtract(mut self, other: &Self) -> Self {
         let (mut lo, mut hi) = ([0u64; N], [0u64; N]);
         crate::const_for!((i in 0..N) {
@@ -749,6 +1017,32 @@ impl<T: MontConfig<N>, const N: usize> Fp<MontBackend<T, N>, N> {
         self.const_subtract_modulus()
     }
 
+    /// Computes `self^exp` by square-and-multiply.
+    const fn const_pow(self, exp: &BigInt<N>) -> Self {
+        let mut res = Self::new_unchecked(T::R);
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            let mut j = 64;
+            while j > 0 {
+                j -= 1;
+                res = res.mul(&res);
+                if (exp.0[i] >> j) & 1 == 1 {
+                    res = res.mul(&self);
+                }
+            }
+        }
+        res
+    }
+
+    /// Computes `self^(-1)` as `self^(MODULUS - 2)`, panicking if `self` is
+    /// zero.
+    const fn const_inverse(self) -> Self {
+        assert!(!self.const_is_zero(), "zero has no inverse");
+        let two = BigInt::<N>::one().const_add_with_carry(&BigInt::one()).0;
+        self.const_pow(&T::MODULUS.const_sub_with_borrow(&two).0)
+    }
+
     const fn const_is_valid(&self) -> bool {
         crate::const_for!((i in 0..N) {
             if (self.0).0[(N - i - 1)] < T::MODULUS.0[(N - i - 1)] {
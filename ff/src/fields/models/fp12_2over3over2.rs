@@ -1,4 +1,4 @@
-use ark_std::Zero;
+use ark_std::{One, Zero};
 
 use super::quadratic_extension::*;
 use crate::{
@@ -210,6 +210,70 @@ impl<P: Fp12Config> CyclotomicMultSubgroup for Fp12<P> {
             self.square_in_place()
         }
     }
+
+    fn cyclotomic_square_batch(&self, count: usize) -> Self {
+        // Squaring and Cyclotomic Subgroups - Koray Karabina
+        //
+        // Writing `self = (g0 + g1 v + g2 v^2) + (g3 + g4 v + g5 v^2) w`, the
+        // squarings only need to track `(g1, g2, g3, g5)`, from which `g4` and
+        // `g0` are recovered at the end.
+        if count < 2 || !characteristic_square_mod_6_is_one(Self::characteristic()) {
+            let mut result = *self;
+            for _ in 0..count {
+                result.cyclotomic_square_in_place();
+            }
+            return result;
+        }
+        let fp2_nr = <P::Fp6Config as Fp6Config>::mul_fp2_by_nonresidue;
+
+        let mut g1 = self.c0.c1;
+        let mut g2 = self.c0.c2;
+        let mut g3 = self.c1.c0;
+        let mut g5 = self.c1.c2;
+        for _ in 0..count {
+            let g1_sq = g1.square();
+            let g2_sq = g2.square();
+            let g3_sq = g3.square();
+            let g5_sq = g5.square();
+            // 2 * g1 * g5 and 2 * g2 * g3
+            let g1_g5 = (g1 + g5).square() - g1_sq - g5_sq;
+            let g2_g3 = (g2 + g3).square() - g2_sq - g3_sq;
+
+            // g1' = 3 * (g3^2 + nr * g2^2) - 2 * g1
+            let t = g3_sq + fp2_nr(g2_sq);
+            g1 = (t - g1).double() + t;
+            // g2' = 3 * (g1^2 + nr * g5^2) - 2 * g2
+            let t = g1_sq + fp2_nr(g5_sq);
+            g2 = (t - g2).double() + t;
+            // g3' = 3 * nr * 2 * g1 * g5 + 2 * g3
+            let t = fp2_nr(g1_g5);
+            g3 = (t + g3).double() + t;
+            // g5' = 3 * 2 * g2 * g3 + 2 * g5
+            g5 = (g2_g3 + g5).double() + g2_g3;
+        }
+
+        // g4 = (nr * g5^2 + 3 * g1^2 - 2 * g2) / (4 * g3) if g3 != 0,
+        // and g4 = 2 * g1 * g5 / g2 otherwise.
+        let g4 = if !g3.is_zero() {
+            let g1_sq = g1.square();
+            let numerator = fp2_nr(g5.square()) + (g1_sq - g2).double() + g1_sq;
+            numerator * g3.double().double().inverse().unwrap()
+        } else if !g2.is_zero() {
+            (g1 * g5).double() * g2.inverse().unwrap()
+        } else {
+            // The compressed representation cannot be decompressed.
+            let mut result = *self;
+            for _ in 0..count {
+                result.cyclotomic_square_in_place();
+            }
+            return result;
+        };
+        // g0 = nr * (2 * g4^2 + g3 * g5 - 3 * g1 * g2) + 1
+        let g1_g2 = g1 * g2;
+        let g0 = fp2_nr((g4.square() - g1_g2).double() - g1_g2 + g3 * g5) + Fp2::one();
+
+        Self::new(Fp6::new(g0, g1, g2), Fp6::new(g3, g4, g5))
+    }
 }
 
 #[cfg(test)]
@@ -150,5 +150,80 @@ impl<P: Fp6Config> Fp6<P> {
     }
 }
 
-// We just use the default algorithms; there don't seem to be any faster ones.
-impl<P: Fp6Config> CyclotomicMultSubgroup for Fp6<P> {}
+impl<P: Fp6Config> CyclotomicMultSubgroup for Fp6<P> {
+    fn cyclotomic_square_in_place(&mut self) -> &mut Self {
+        // Faster Squaring in the Cyclotomic Subgroup of Sixth Degree Extensions
+        // - Robert Granger and Michael Scott
+        //
+        // If `p = 1 (mod 6)`, then for `x = a + b * v + c * v^2` in the cyclotomic
+        // subgroup, `x^2 = 3 * (a^2 + nr * c^2 * v + b^2 * v^2) - 2 * x^(p^3)`,
+        // where `x^(p^3) = x^{-1}` is cheap to compute via the Frobenius map.
+        if characteristic_mod_6_is_one(Self::characteristic()) {
+            let mut conjugate = *self;
+            conjugate.frobenius_map(3);
+
+            let t0 = self.c0.square();
+            let t1 = P::mul_fp2_by_nonresidue(self.c2.square());
+            let t2 = self.c1.square();
+
+            self.c0 = (t0 - conjugate.c0).double() + t0;
+            self.c1 = (t1 - conjugate.c1).double() + t1;
+            self.c2 = (t2 - conjugate.c2).double() + t2;
+            self
+        } else {
+            self.square_in_place()
+        }
+    }
+}
+
+/// Returns whether `characteristic = 1 (mod 6)`, given its little-endian limbs.
+pub const fn characteristic_mod_6_is_one(characteristic: &[u64]) -> bool {
+    // 2^64 = 4 (mod 6) and 4^k = 4 (mod 6) for k >= 1, see
+    // `characteristic_square_mod_6_is_one`.
+    let mut char_mod_6 = 0u64;
+    crate::const_for!((i in 0..(characteristic.len())) {
+        char_mod_6 += if i == 0 {
+            characteristic[i] % 6
+        } else {
+            (4 * (characteristic[i] % 6)) % 6
+        };
+    });
+    char_mod_6 % 6 == 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::characteristic_mod_6_is_one;
+    use ark_std::{test_rng, UniformRand};
+    use ark_test_curves::{bls12_381::Fq6, CyclotomicMultSubgroup, Field};
+
+    #[test]
+    fn test_characteristic_mod_6_is_one() {
+        assert!(!characteristic_mod_6_is_one(&[36]));
+        assert!(characteristic_mod_6_is_one(&[37]));
+        assert!(!characteristic_mod_6_is_one(&[41]));
+        assert!(!characteristic_mod_6_is_one(&[36, 41]));
+        assert!(characteristic_mod_6_is_one(&[41, 41]));
+        assert!(characteristic_mod_6_is_one(&[1, u64::MAX]));
+    }
+
+    #[test]
+    fn test_cyclotomic_square() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            // Map into the cyclotomic subgroup by raising to `(p^3 - 1)(p + 1)`.
+            let f = Fq6::rand(&mut rng);
+            let mut g = f;
+            g.frobenius_map(3);
+            g *= f.inverse().unwrap();
+            let mut h = g;
+            h.frobenius_map(1);
+            h *= g;
+
+            let mut expected = h;
+            expected.square_in_place();
+            assert_eq!(*h.clone().cyclotomic_square_in_place(), expected);
+            assert_eq!(h.cyclotomic_square(), expected);
+        }
+    }
+}
@@ -12,6 +12,7 @@ use ark_std::{
 };
 
 use num_traits::{One, Zero};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 use ark_std::rand::{
@@ -165,6 +166,21 @@ impl<P: QuadExtConfig> QuadExtField<P> {
         result
     }
 
+    /// Computes the inverse as `a^{-1} = a.conjugate() / Norm(a)`, which
+    /// costs a single inversion in `P::BaseField`. This is how
+    /// [`Field::inverse`] is implemented for `QuadExtField`.
+    ///
+    /// Returns `None` if `self` is zero, as this is the only element whose
+    /// norm is zero.
+    pub fn inverse_via_norm(&self) -> Option<Self> {
+        // Guide to Pairing-based Cryptography, Algorithm 5.19.
+        self.norm().inverse().map(|norm_inv| {
+            let c0 = self.c0 * &norm_inv;
+            let c1 = -(self.c1 * &norm_inv);
+            Self::new(c0, c1)
+        })
+    }
+
     /// In-place multiply both coefficients `c0` & `c1` of the quadratic
     /// extension field by an element from the base field.
     pub fn mul_assign_by_basefield(&mut self, element: &P::BaseField) {
@@ -203,6 +219,7 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
 
     const ZERO: Self = Self::new(P::BaseField::ZERO, P::BaseField::ZERO);
     const ONE: Self = Self::new(P::BaseField::ONE, P::BaseField::ZERO);
+    const NEG_ONE: Self = Self::new(P::BaseField::NEG_ONE, P::BaseField::ZERO);
 
     fn extension_degree() -> u64 {
         2 * P::BaseField::extension_degree()
@@ -219,6 +236,14 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
             .chain(self.c1.to_base_prime_field_elements())
     }
 
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+
+    fn vartime_eq(&self, other: &Self) -> bool {
+        self.c0.vartime_eq(&other.c0) && self.c1.vartime_eq(&other.c1)
+    }
+
     fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
         if elems.len() != (Self::extension_degree() as usize) {
             return None;
@@ -248,6 +273,11 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         self
     }
 
+    fn conditional_negate(&mut self, flag: bool) {
+        self.c0.conditional_negate(flag);
+        self.c1.conditional_negate(flag);
+    }
+
     fn square(&self) -> Self {
         let mut result = *self;
         result.square_in_place();
@@ -272,6 +302,13 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         Self::from_random_bytes_with_flags::<EmptyFlags>(bytes).map(|f| f.0)
     }
 
+    fn from_random_bytes_consumed(bytes: &[u8]) -> Option<(Self, usize)> {
+        // The coefficients are read back to back, so no byte is skipped.
+        let (c0, consumed0) = P::BaseField::from_random_bytes_consumed(bytes)?;
+        let (c1, consumed1) = P::BaseField::from_random_bytes_consumed(&bytes[consumed0..])?;
+        Some((QuadExtField::new(c0, c1), consumed0 + consumed1))
+    }
+
     fn square_in_place(&mut self) -> &mut Self {
         // (c0, c1)^2 = (c0 + x*c1)^2
         //            = c0^2 + 2 c0 c1 x + c1^2 x^2
@@ -327,21 +364,29 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
     }
 
     fn inverse(&self) -> Option<Self> {
+        self.inverse_via_norm()
+    }
+
+    fn inverse_zeroizing(&self) -> Option<Self> {
         if self.is_zero() {
             None
         } else {
-            // Guide to Pairing-based Cryptography, Algorithm 5.19.
-            // v1 = c1.square()
-            let v1 = self.c1.square();
-            // v0 = c0.square() - beta * v1
+            // Same as `inverse`, but zeroizing the intermediate values.
+            let mut v1 = self.c1.square();
+            let mut c0_squared = self.c0.square();
             let mut v0 = v1;
-            P::sub_and_mul_base_field_by_nonresidue(&mut v0, &self.c0.square());
+            P::sub_and_mul_base_field_by_nonresidue(&mut v0, &c0_squared);
 
-            v0.inverse().map(|v1| {
-                let c0 = self.c0 * &v1;
-                let c1 = -(self.c1 * &v1);
+            let result = v0.inverse_zeroizing().map(|mut v0_inv| {
+                let c0 = self.c0 * &v0_inv;
+                let c1 = -(self.c1 * &v0_inv);
+                v0_inv.zeroize();
                 Self::new(c0, c1)
-            })
+            });
+            v0.zeroize();
+            v1.zeroize();
+            c0_squared.zeroize();
+            result
         }
     }
 
@@ -355,6 +400,10 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
     }
 
     fn frobenius_map(&mut self, power: usize) {
+        let power = power % Self::extension_degree() as usize;
+        if power == 0 {
+            return;
+        }
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
         P::mul_base_field_by_frob_coeff(&mut self.c1, power);
@@ -374,6 +423,12 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         self.norm().legendre()
     }
 
+    fn is_square(&self) -> bool {
+        // An element is a square iff its norm is a square in the base field,
+        // for the same reason as in `legendre` above.
+        self.norm().is_square()
+    }
+
     fn sqrt(&self) -> Option<Self> {
         // Square root based on the complex method. See
         // https://eprint.iacr.org/2012/685.pdf (page 15, algorithm 8)
@@ -470,6 +525,24 @@ impl<P: QuadExtConfig> Zeroize for QuadExtField<P> {
     }
 }
 
+impl<P: QuadExtConfig> ConstantTimeEq for QuadExtField<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Field::ct_eq(self, other)
+    }
+}
+
+impl<P: QuadExtConfig> ConditionallySelectable for QuadExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
 impl<P: QuadExtConfig> From<u128> for QuadExtField<P> {
     fn from(other: u128) -> Self {
         Self::new(other.into(), P::BaseField::ZERO)
@@ -821,4 +894,85 @@ mod quad_ext_tests {
             );
         }
     }
+
+    #[test]
+    fn test_inverse_via_norm() {
+        use ark_test_curves::{bls12_381::Fq12, UniformRand};
+
+        assert_eq!(Fq2::zero().inverse_via_norm(), None);
+        assert_eq!(Fq12::zero().inverse_via_norm(), None);
+
+        // Compare against Fermat inversion `a^{-1} = a^(q^2 - 2)` in `Fq2`.
+        let order: num_bigint::BigUint = Fq::characteristic_biguint().pow(2) - 2u8;
+        let exp = order.to_u64_digits();
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let a = Fq2::rand(&mut rng);
+            let a_inv = a.inverse_via_norm().unwrap();
+            assert_eq!(a_inv, a.pow(&exp));
+            assert_eq!(Some(a_inv), a.inverse());
+            assert!((a * a_inv).is_one());
+
+            let b = Fq12::rand(&mut rng);
+            let b_inv = b.inverse_via_norm().unwrap();
+            assert_eq!(Some(b_inv), b.inverse());
+            assert!((b * b_inv).is_one());
+        }
+    }
+
+    #[test]
+    fn test_validate_canonical() {
+        use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+        use ark_test_curves::{BigInteger, PrimeField, UniformRand};
+
+        let mut rng = test_rng();
+        let a = Fq2::rand(&mut rng);
+        let mut bytes = Vec::new();
+        a.serialize_compressed(&mut bytes).unwrap();
+        let b = Fq2::deserialize_compressed(&bytes[..]).unwrap();
+        assert!(b.validate_canonical());
+        assert!(Fq2::zero().validate_canonical());
+
+        // `p + x` represents the same residue as `x`, but is not canonical.
+        let mut non_canonical = a.c1.0;
+        assert!(!non_canonical.add_with_carry(&Fq::MODULUS));
+        let c = Fq2::new(a.c0, Fq::new_unchecked(non_canonical));
+        assert!(!c.validate_canonical());
+        let c = Fq2::new(Fq::new_unchecked(Fq::MODULUS), a.c1);
+        assert!(!c.validate_canonical());
+    }
+
+    #[test]
+    fn test_nonresidue_hooks() {
+        use ark_test_curves::{
+            bls12_381::{Fq12Config, Fq2Config},
+            Fp12ConfigWrapper, Fp2ConfigWrapper, QuadExtConfig, UniformRand,
+        };
+
+        // The specialized hooks must agree with multiplying by `NONRESIDUE`.
+        fn check<P: QuadExtConfig>() {
+            let mut rng = test_rng();
+            for _ in 0..100 {
+                let x = P::BaseField::rand(&mut rng);
+                let y = P::BaseField::rand(&mut rng);
+                let y_nr = y * P::NONRESIDUE;
+
+                let mut z = y;
+                P::mul_base_field_by_nonresidue_in_place(&mut z);
+                assert_eq!(z, y_nr);
+                let mut z = y;
+                P::mul_base_field_by_nonresidue_and_add(&mut z, &x);
+                assert_eq!(z, x + y_nr);
+                let mut z = y;
+                P::mul_base_field_by_nonresidue_plus_one_and_add(&mut z, &x);
+                assert_eq!(z, x + y_nr + y);
+                let mut z = y;
+                P::sub_and_mul_base_field_by_nonresidue(&mut z, &x);
+                assert_eq!(z, x - y_nr);
+            }
+        }
+
+        check::<Fp2ConfigWrapper<Fq2Config>>();
+        check::<Fp12ConfigWrapper<Fq12Config>>();
+    }
 }
@@ -0,0 +1,199 @@
+//! [`serde`] support for field elements, enabled by the `serde` feature.
+//!
+//! Field elements are serialized to their canonical little-endian byte form,
+//! i.e. the output of [`CanonicalSerialize::serialize_compressed`]. Formats
+//! that are human-readable, such as JSON, encode these bytes as a lower-case
+//! hex string, while binary formats encode them as raw bytes. Deserialization
+//! rejects encodings of values that are not smaller than the modulus.
+
+use crate::{CubicExtConfig, CubicExtField, Fp, FpConfig, QuadExtConfig, QuadExtField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, marker::PhantomData, string::String, vec::Vec};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+fn serialize<T: CanonicalSerialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut bytes = Vec::with_capacity(value.compressed_size());
+    value
+        .serialize_compressed(&mut bytes)
+        .map_err(serde::ser::Error::custom)?;
+    if serializer.is_human_readable() {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let hex = bytes
+            .iter()
+            .flat_map(|b| [b >> 4, b & 0xf])
+            .map(|nibble| char::from(HEX_DIGITS[usize::from(nibble)]))
+            .collect::<String>();
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+fn deserialize<'de, T: CanonicalDeserialize, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let visitor = BytesVisitor(PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_bytes(visitor)
+    }
+}
+
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<T: CanonicalDeserialize> BytesVisitor<T> {
+    fn from_bytes<E: de::Error>(bytes: &[u8]) -> Result<T, E> {
+        let mut reader = bytes;
+        let value = T::deserialize_compressed(&mut reader).map_err(E::custom)?;
+        if !reader.is_empty() {
+            return Err(E::invalid_length(bytes.len(), &"a canonical field element"));
+        }
+        Ok(value)
+    }
+}
+
+impl<'de, T: CanonicalDeserialize> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the canonical little-endian bytes of a field element")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        // `u8::from_str_radix` also accepts a leading sign, so the digits are
+        // checked first.
+        if v.len() % 2 != 0 || !v.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+        }
+        let bytes = v
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                let pair = core::str::from_utf8(pair).ok()?;
+                u8::from_str_radix(pair, 16).ok()
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))?;
+        Self::from_bytes(&bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        Self::from_bytes(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl<P: FpConfig<N>, const N: usize> Serialize for Fp<P, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, P: FpConfig<N>, const N: usize> Deserialize<'de> for Fp<P, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<P: QuadExtConfig> Serialize for QuadExtField<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, P: QuadExtConfig> Deserialize<'de> for QuadExtField<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<P: CubicExtConfig> Serialize for CubicExtField<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, P: CubicExtConfig> Deserialize<'de> for CubicExtField<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_std::{format, string::String, test_rng, vec, vec::Vec, UniformRand};
+    use ark_test_curves::{
+        bls12_381::{Fq, Fq12, Fq2, Fr},
+        BigInteger, PrimeField,
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+
+    fn test_round_trip<T: Serialize + DeserializeOwned + PartialEq + core::fmt::Debug>(value: T) {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            test_round_trip(Fr::rand(&mut rng));
+            test_round_trip(Fq::rand(&mut rng));
+            test_round_trip(Fq2::rand(&mut rng));
+            test_round_trip(Fq12::rand(&mut rng));
+        }
+    }
+
+    #[test]
+    fn test_serde_format() {
+        // Human-readable formats use the hex encoding of the little-endian bytes.
+        let json = serde_json::to_string(&Fr::from(0x1234u64)).unwrap();
+        assert_eq!(json, format!("\"3412{}\"", "0".repeat(60)));
+
+        // Binary formats use the raw bytes.
+        let bytes = bincode::serialize(&Fr::from(1u64)).unwrap();
+        let mut expected = vec![32, 0, 0, 0, 0, 0, 0, 0, 1];
+        expected.resize(40, 0);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_serde_rejects_invalid() {
+        let hex = |bytes: &[u8]| {
+            let hex = bytes
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>();
+            format!("\"{}\"", hex)
+        };
+
+        let modulus = Fr::MODULUS.to_bytes_le();
+        assert!(serde_json::from_str::<Fr>(&hex(&modulus)).is_err());
+        let bytes = bincode::serialize(&modulus).unwrap();
+        assert!(bincode::deserialize::<Fr>(&bytes).is_err());
+
+        // Wrong lengths and invalid hex digits are rejected.
+        assert!(serde_json::from_str::<Fr>(&hex(&[0; 31])).is_err());
+        assert!(serde_json::from_str::<Fr>(&hex(&[0; 33])).is_err());
+        assert!(serde_json::from_str::<Fr>(&format!("\"{}\"", "g".repeat(64))).is_err());
+        assert!(serde_json::from_str::<Fr>("\"0\"").is_err());
+        assert!(serde_json::from_str::<Fr>(&format!("\"+1{}\"", "0".repeat(62))).is_err());
+    }
+}
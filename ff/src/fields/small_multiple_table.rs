@@ -0,0 +1,57 @@
+use crate::fields::Field;
+use ark_std::vec::Vec;
+
+/// A table of precomputed small multiples of a fixed base, used to speed up
+/// repeated multiplications of that base by small integers.
+///
+/// The table stores `k * base` for every `k` in `0..max`, so that such a
+/// multiplication is a single lookup. Larger multipliers fall back to a
+/// field multiplication.
+///
+/// ```
+/// # use ark_ff::SmallMultipleTable;
+/// # use ark_std::{UniformRand, test_rng};
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let mut rng = test_rng();
+/// let base = F::rand(&mut rng);
+/// let table = SmallMultipleTable::new(&base, 16);
+/// assert_eq!(table.get(5), base * F::from(5u64));
+/// assert_eq!(table.get(100), base * F::from(100u64));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SmallMultipleTable<F: Field> {
+    base: F,
+    table: Vec<F>,
+}
+
+impl<F: Field> SmallMultipleTable<F> {
+    /// Precomputes the multiples `[0, base, 2 * base, ..., (max - 1) * base]`.
+    pub fn new(base: &F, max: u64) -> Self {
+        let max = usize::try_from(max).expect("max does not fit into usize");
+        let mut table = Vec::with_capacity(max);
+        let mut multiple = F::zero();
+        for _ in 0..max {
+            table.push(multiple);
+            multiple += base;
+        }
+        Self { base: *base, table }
+    }
+
+    /// Returns the base of this table.
+    pub fn base(&self) -> &F {
+        &self.base
+    }
+
+    /// Returns the number of precomputed multiples.
+    pub fn max(&self) -> u64 {
+        self.table.len() as u64
+    }
+
+    /// Returns `k * base`, looking it up in the table if `k < self.max()`.
+    pub fn get(&self, k: u64) -> F {
+        match usize::try_from(k).ok().and_then(|k| self.table.get(k)) {
+            Some(multiple) => *multiple,
+            None => self.base * F::from(k),
+        }
+    }
+}
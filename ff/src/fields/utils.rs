@@ -12,3 +12,17 @@ pub fn k_adicity(k: u64, mut n: u64) -> u32 {
     }
     r
 }
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of `bytes`.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            // Subtract the reflected polynomial iff the lowest bit is set.
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
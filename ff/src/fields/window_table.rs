@@ -0,0 +1,102 @@
+use crate::{fields::Field, BitIteratorLE};
+use ark_std::vec::Vec;
+
+/// A table of precomputed powers of a fixed base, used to speed up repeated
+/// exponentiations of that base.
+///
+/// The exponent is split into windows of `window_bits` bits. For the `i`-th
+/// window, the table stores `base^(j * 2^(i * window_bits))` for every
+/// `j` in `0..2^window_bits`, so that an exponentiation costs one
+/// multiplication per window and no squarings.
+///
+/// ```
+/// # use ark_ff::{Field, WindowTable};
+/// # use ark_std::{UniformRand, test_rng};
+/// # use ark_test_curves::bls12_381::Fr as F;
+/// let mut rng = test_rng();
+/// let base = F::rand(&mut rng);
+/// let table = WindowTable::new(&base, 4, 64);
+/// assert_eq!(table.pow([12345u64]), base.pow([12345u64]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowTable<F: Field> {
+    window_bits: usize,
+    max_exp_bits: usize,
+    table: Vec<Vec<F>>,
+}
+
+impl<F: Field> WindowTable<F> {
+    /// Precomputes the table for `base`, supporting exponents of at most
+    /// `max_exp_bits` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_bits` is zero or larger than 16.
+    pub fn new(base: &F, window_bits: usize, max_exp_bits: usize) -> Self {
+        assert!(
+            (1..=16).contains(&window_bits),
+            "window_bits must be between 1 and 16"
+        );
+        let num_windows = (max_exp_bits + window_bits - 1) / window_bits;
+        let window_size = 1 << window_bits;
+
+        let mut table = Vec::with_capacity(num_windows);
+        // `window_base = base^(2^(i * window_bits))` for the current window `i`.
+        let mut window_base = *base;
+        for _ in 0..num_windows {
+            let mut row = Vec::with_capacity(window_size);
+            row.push(F::one());
+            for j in 1..window_size {
+                row.push(row[j - 1] * &window_base);
+            }
+            window_base = row[window_size - 1] * &window_base;
+            table.push(row);
+        }
+
+        Self {
+            window_bits,
+            max_exp_bits,
+            table,
+        }
+    }
+
+    /// Returns the width of each window, in bits.
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// Returns the maximum bit length of exponents supported by this table.
+    pub fn max_exp_bits(&self) -> usize {
+        self.max_exp_bits
+    }
+
+    /// Exponentiates the base of this table by a number represented with
+    /// `u64` limbs, least significant limb first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` has more significant bits than the table covers, i.e.
+    /// more than `self.max_exp_bits()` rounded up to a multiple of
+    /// `self.window_bits()`.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> F {
+        let mut res = F::one();
+        let mut bits = BitIteratorLE::without_trailing_zeros(exp).peekable();
+        let mut window = 0;
+        while bits.peek().is_some() {
+            let row = self
+                .table
+                .get(window)
+                .expect("exponent is larger than the table supports");
+            let digit = bits
+                .by_ref()
+                .take(self.window_bits)
+                .enumerate()
+                .fold(0usize, |acc, (i, bit)| acc | (usize::from(bit) << i));
+            if digit != 0 {
+                res *= &row[digit];
+            }
+            window += 1;
+        }
+        res
+    }
+}
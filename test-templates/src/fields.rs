@@ -17,6 +17,24 @@ impl ark_serialize::Flags for DummyFlags {
     }
 }
 
+/// Flags that occupy the `BITS` most significant bits of a byte, and are
+/// encoded by setting all of them.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TopBitsFlags<const BITS: usize>;
+
+impl<const BITS: usize> ark_serialize::Flags for TopBitsFlags<BITS> {
+    const BIT_SIZE: usize = BITS;
+
+    fn u8_bitmask(&self) -> u8 {
+        !u8::MAX.checked_shr(BITS as u32).unwrap_or(0)
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        let mask = Self.u8_bitmask();
+        (value & mask == mask).then(|| Self)
+    }
+}
+
 pub fn sum_of_products_test_helper<F: ark_ff::Field, const N: usize>(rng: &mut impl Rng) {
     let a: [_; N] = core::array::from_fn(|_| F::rand(rng));
     let b: [_; N] = core::array::from_fn(|_| F::rand(rng));
@@ -47,10 +65,80 @@ pub fn prime_field_sum_of_products_test_helper<F: ark_ff::PrimeField, const N: u
     assert_eq!(result_1, result_2, "length: {N}");
 }
 
+/// Computes the chi-squared statistic of `samples` against the uniform
+/// distribution, after bucketing each sample by its canonical representative
+/// modulo `buckets`.
+///
+/// For uniformly distributed samples, the result follows a chi-squared
+/// distribution with `buckets - 1` degrees of freedom.
+pub fn chi_squared_uniformity<F: ark_ff::PrimeField>(samples: &[F], buckets: usize) -> f64 {
+    assert!(buckets > 1, "at least two buckets are required");
+    let mut counts = vec![0usize; buckets];
+    for sample in samples {
+        let bucket = sample
+            .into_bigint()
+            .as_ref()
+            .iter()
+            .rev()
+            .fold(0u128, |acc, limb| {
+                ((acc << 64) | u128::from(*limb)) % buckets as u128
+            });
+        counts[bucket as usize] += 1;
+    }
+
+    // The modulus is not a multiple of `buckets`, so the lower buckets receive
+    // one extra value; this bias is negligible for large moduli.
+    let expected = samples.len() as f64 / buckets as f64;
+    counts
+        .iter()
+        .map(|&count| {
+            let diff = count as f64 - expected;
+            diff * diff / expected
+        })
+        .sum()
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __test_field {
     ($field: ty) => {
+        #[test]
+        fn test_characteristic() {
+            use ark_ff::{Field, PrimeField};
+            use $crate::num_bigint::BigUint;
+            let characteristic = <$field as Field>::BasePrimeField::MODULUS;
+            assert_eq!(<$field>::characteristic_bigint(), characteristic);
+            assert_eq!(<$field>::characteristic_biguint(), BigUint::from(characteristic));
+            assert_eq!(
+                <$field>::characteristic_biguint(),
+                BigUint::from_slice(
+                    &<$field>::characteristic()
+                        .iter()
+                        .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+                        .collect::<Vec<_>>()
+                )
+            );
+        }
+
+        #[test]
+        fn test_ct_eq_and_vartime_eq() {
+            use ark_ff::Field;
+            use ark_std::{One, UniformRand, Zero};
+            let mut rng = ark_std::test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let b = <$field>::rand(&mut rng);
+                for (x, y) in [(a, a), (a, b), (a, a + <$field>::one()), (<$field>::zero(), a)] {
+                    let expected = x == y;
+                    assert_eq!(bool::from(x.ct_eq(&y)), expected);
+                    assert_eq!(x.vartime_eq(&y), expected);
+                }
+            }
+            assert!(bool::from(<$field>::zero().ct_eq(&<$field>::zero())));
+            assert!(<$field>::one().vartime_eq(&<$field>::one()));
+            assert!(!<$field>::one().vartime_eq(&<$field>::zero()));
+        }
+
         #[test]
         pub fn test_frobenius() {
             use ark_ff::Field;
@@ -77,6 +165,37 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_frobenius_map_period() {
+            use ark_ff::Field;
+            use ark_std::UniformRand;
+            let mut rng = ark_std::test_rng();
+            let degree = <$field>::extension_degree() as usize;
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+
+                // Applying the Frobenius map `degree` times is the identity.
+                let mut b = a;
+                for _ in 0..degree {
+                    b.frobenius_map_in_place(1);
+                }
+                assert_eq!(b, a);
+
+                // Powers are reduced modulo the extension degree.
+                for power in 0..degree {
+                    let mut expected = a;
+                    expected.frobenius_map(power);
+                    for multiple in 1..3 {
+                        let mut b = a;
+                        b.frobenius_map(power + multiple * degree);
+                        assert_eq!(b, expected, "failed on power {}", power);
+                    }
+                }
+                assert_eq!(*a.clone().frobenius_map_in_place(degree), a);
+            }
+        }
+
         #[test]
         fn test_serialization() {
             use ark_serialize::*;
@@ -135,6 +254,137 @@ macro_rules! __test_field {
 
         }
 
+        #[test]
+        fn test_from_random_bytes_consumed() {
+            use ark_serialize::CanonicalSerialize;
+            let mut rng = test_rng();
+            let size = <$field>::zero().compressed_size();
+            // The coefficients over the base prime field are read back to back.
+            let base_bits = <<$field as Field>::BasePrimeField as ark_ff::PrimeField>::MODULUS_BIT_SIZE;
+            let num_bytes = <$field>::extension_degree() as usize * ((base_bits + 7) / 8) as usize;
+            for len in [0, 1, size / 2, size - 1, size, size + 1, 2 * size] {
+                for _ in 0..10 {
+                    let mut bytes = (0..len).map(|_| rng.gen()).collect::<Vec<u8>>();
+                    let result = <$field>::from_random_bytes_consumed(&bytes);
+                    if let Some((f, consumed)) = result {
+                        assert_eq!(consumed, len.min(num_bytes));
+                        assert_eq!(<$field>::from_random_bytes_consumed(&bytes[..consumed]), result);
+                        // Bytes past those consumed do not affect the result.
+                        bytes[consumed..].iter_mut().for_each(|b| *b = rng.gen());
+                        assert_eq!(<$field>::from_random_bytes_consumed(&bytes), Some((f, consumed)));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_serialize_with_checksum() {
+            use ark_serialize::{CanonicalSerialize, SerializationError};
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let mut bytes = Vec::new();
+                a.serialize_with_checksum(&mut bytes).unwrap();
+                assert_eq!(bytes.len(), a.compressed_size() + 4);
+                assert_eq!(<$field>::deserialize_with_checksum(&bytes[..]).unwrap(), a);
+
+                // Flipping any bit is detected.
+                let i = rng.gen_range(0..bytes.len());
+                bytes[i] ^= 1 << rng.gen_range(0..8);
+                assert!(matches!(
+                    <$field>::deserialize_with_checksum(&bytes[..]),
+                    Err(SerializationError::InvalidData)
+                ));
+            }
+
+            // Truncated input is rejected.
+            let mut bytes = Vec::new();
+            <$field>::one().serialize_with_checksum(&mut bytes).unwrap();
+            assert!(<$field>::deserialize_with_checksum(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        #[test]
+        fn test_neg_one() {
+            assert_eq!(<$field>::NEG_ONE, -<$field>::one());
+            assert!((<$field>::NEG_ONE + <$field>::ONE).is_zero());
+        }
+
+        #[test]
+        fn test_conditional_negate() {
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let mut b = a;
+                b.conditional_negate(true);
+                assert_eq!(b, -a);
+                b = a;
+                b.conditional_negate(false);
+                assert_eq!(b, a);
+            }
+            for a in [<$field>::zero(), <$field>::one()] {
+                let mut b = a;
+                b.conditional_negate(true);
+                assert_eq!(b, -a);
+                b = a;
+                b.conditional_negate(false);
+                assert_eq!(b, a);
+            }
+        }
+
+        #[test]
+        fn test_classify_01() {
+            assert_eq!(<$field>::zero().classify_01(), 0);
+            assert_eq!(<$field>::one().classify_01(), 1);
+            assert_eq!((-<$field>::one()).classify_01(), 2);
+            assert_eq!(<$field>::from(2u8).classify_01(), 2);
+
+            // Elements that only differ from zero or one in the last
+            // coefficient are classified as other.
+            let degree = <$field>::extension_degree() as usize;
+            let mut coeffs = vec![<<$field as Field>::BasePrimeField>::zero(); degree];
+            coeffs[degree - 1] = <<$field as Field>::BasePrimeField>::from(2u8);
+            let x = <$field>::from_base_prime_field_elems(&coeffs).unwrap();
+            assert_eq!(x.classify_01(), 2);
+            assert_eq!((x + <$field>::one()).classify_01(), 2);
+
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let x = <$field>::rand(&mut rng);
+                let expected = if x.is_zero() { 0 } else if x.is_one() { 1 } else { 2 };
+                assert_eq!(x.classify_01(), expected);
+            }
+        }
+
+        #[test]
+        fn test_from_signed() {
+            let one = <$field>::one();
+            assert_eq!(<$field>::from(-3i64), -<$field>::from(3u64));
+            assert_eq!(<$field>::from(0i64), <$field>::zero());
+
+            assert_eq!(<$field>::from(i8::MIN), -<$field>::from(1u8 << 7));
+            assert_eq!(<$field>::from(i8::MAX), <$field>::from(i8::MAX as u8));
+            assert_eq!(<$field>::from(-1i8), -one);
+            assert_eq!(<$field>::from(i16::MIN), -<$field>::from(1u16 << 15));
+            assert_eq!(<$field>::from(i16::MAX), <$field>::from(i16::MAX as u16));
+            assert_eq!(<$field>::from(-1i16), -one);
+            assert_eq!(<$field>::from(i32::MIN), -<$field>::from(1u32 << 31));
+            assert_eq!(<$field>::from(i32::MAX), <$field>::from(i32::MAX as u32));
+            assert_eq!(<$field>::from(-1i32), -one);
+            assert_eq!(<$field>::from(i64::MIN), -<$field>::from(1u64 << 63));
+            assert_eq!(<$field>::from(i64::MAX), <$field>::from(i64::MAX as u64));
+            assert_eq!(<$field>::from(-1i64), -one);
+            assert_eq!(<$field>::from(i128::MIN), -<$field>::from(1u128 << 127));
+            assert_eq!(<$field>::from(i128::MAX), <$field>::from(i128::MAX as u128));
+            assert_eq!(<$field>::from(-1i128), -one);
+
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let x: i64 = rng.gen::<i64>().max(-i64::MAX);
+                assert_eq!(<$field>::from(x) + <$field>::from(-x), <$field>::zero());
+                assert_eq!(<$field>::from(x), <$field>::from(i128::from(x)));
+            }
+        }
+
         #[test]
         fn test_add_properties() {
             use ark_std::UniformRand;
@@ -203,6 +453,18 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_inverse_zeroizing() {
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            assert!(<$field>::zero().inverse_zeroizing().is_none());
+            assert_eq!(<$field>::one().inverse_zeroizing(), Some(<$field>::one()));
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                assert_eq!(a.inverse_zeroizing(), a.inverse());
+            }
+        }
+
         #[test]
         fn test_mul_properties() {
             use ark_std::UniformRand;
@@ -301,6 +563,103 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_pow_trivial_exponents() {
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            let empty: [u64; 0] = [];
+            for a in [<$field>::zero(), <$field>::one(), <$field>::rand(&mut rng)] {
+                // An empty exponent is zero.
+                assert_eq!(a.pow(empty), <$field>::one());
+                assert_eq!(a.pow([0]), <$field>::one());
+                assert_eq!(a.pow([0, 0, 0]), <$field>::one());
+                assert_eq!(a.pow([1]), a);
+                assert_eq!(a.pow([1, 0, 0]), a);
+                assert_eq!(a.pow([2]), a.square());
+            }
+        }
+
+        #[test]
+        fn test_product_of_powers() {
+            let mut rng = test_rng();
+            for _ in 0..(ITERATIONS / 10) {
+                let bases: Vec<$field> = (0..4).map(|_| <$field>::rand(&mut rng)).collect();
+                let exps: Vec<[u64; 2]> = (0..4).map(|_| [rng.gen(), rng.gen()]).collect();
+                let expected: $field = bases.iter().zip(&exps).map(|(b, e)| b.pow(e)).product();
+                assert_eq!(<$field>::product_of_powers(&bases, &exps), expected);
+            }
+
+            // Exponents of different lengths, including zero.
+            let a = <$field>::rand(&mut rng);
+            let b = <$field>::rand(&mut rng);
+            let exps: [&[u64]; 2] = [&[5], &[0, 1]];
+            assert_eq!(<$field>::product_of_powers(&[a, b], &exps), a.pow([5]) * b.pow([0, 1]));
+            let exps: [&[u64]; 2] = [&[0], &[]];
+            assert_eq!(<$field>::product_of_powers(&[a, b], &exps), <$field>::one());
+            assert_eq!(<$field>::product_of_powers::<[u64; 1]>(&[], &[]), <$field>::one());
+        }
+
+        #[test]
+        #[should_panic(expected = "`bases` and `exps` must have the same length")]
+        fn test_product_of_powers_length_mismatch() {
+            <$field>::product_of_powers(&[<$field>::one()], &[[1u64], [2]]);
+        }
+
+        #[test]
+        fn test_pow2k() {
+            let mut rng = test_rng();
+            for _ in 0..(ITERATIONS / 10) {
+                let a = <$field>::rand(&mut rng);
+                for k in 0..64 {
+                    let expected = a.pow([1u64 << k]);
+                    assert_eq!(a.pow2k(k), expected);
+                    let mut b = a;
+                    b.pow2k_in_place(k);
+                    assert_eq!(b, expected);
+                }
+            }
+        }
+
+        #[test]
+        fn test_window_table() {
+            use ark_ff::WindowTable;
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            for window_bits in [2, 4, 8] {
+                let a = <$field>::rand(&mut rng);
+                let table = WindowTable::new(&a, window_bits, 128);
+                assert_eq!(table.pow([0u64]), <$field>::one());
+                assert_eq!(table.pow([1u64]), a);
+                assert_eq!(table.pow([u64::MAX, u64::MAX]), a.pow([u64::MAX, u64::MAX]));
+                for _ in 0..(ITERATIONS / 100) {
+                    let e: [u64; 2] = rng.gen();
+                    assert_eq!(table.pow(e), a.pow(e), "window bits: {}", window_bits);
+                }
+            }
+        }
+
+        #[test]
+        fn test_small_multiple_table() {
+            use ark_ff::SmallMultipleTable;
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            let a = <$field>::rand(&mut rng);
+            let table = SmallMultipleTable::new(&a, 32);
+            assert_eq!(table.max(), 32);
+            for k in 0..64 {
+                assert_eq!(table.get(k), a * <$field>::from(k));
+            }
+            assert_eq!(table.get(u64::MAX), a * <$field>::from(u64::MAX));
+            for _ in 0..ITERATIONS {
+                let k: u64 = rng.gen();
+                assert_eq!(table.get(k), a * <$field>::from(k));
+            }
+
+            let empty = SmallMultipleTable::new(&a, 0);
+            assert_eq!(empty.get(0), <$field>::zero());
+            assert_eq!(empty.get(3), a + a + a);
+        }
+
         #[test]
         fn test_sum_of_products_tests() {
             use ark_std::{UniformRand, rand::Rng};
@@ -320,6 +679,113 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_sum_of_products_iter() {
+            let rng = &mut test_rng();
+            let a: Vec<_> = (0..50).map(|_| <$field>::rand(rng)).collect();
+            let b: Vec<_> = (0..40).map(|_| <$field>::rand(rng)).collect();
+            for len in [0, 1, 15, 16, 17, 32, 40] {
+                let expected = a[..len].iter().zip(&b).map(|(a, b)| *a * b).sum::<$field>();
+                let result = <$field>::sum_of_products_iter(a[..len].iter().copied(), b.iter().copied());
+                assert_eq!(result, expected, "length: {len}");
+            }
+            // Stops at the shorter of the two iterators.
+            let expected = a.iter().zip(&b).map(|(a, b)| *a * b).sum::<$field>();
+            assert_eq!(<$field>::sum_of_products_iter(a.clone(), b.clone()), expected);
+            assert_eq!(<$field>::sum_of_products_iter(b.clone(), a.clone()), expected);
+
+            // Lazily generated coefficients.
+            let x = <$field>::rand(rng);
+            let powers = core::iter::successors(Some(<$field>::one()), |p| Some(*p * x));
+            let mut coeffs = [<$field>::zero(); 8];
+            let mut powers_array = [<$field>::zero(); 8];
+            for (i, (c, p)) in coeffs.iter_mut().zip(&mut powers_array).enumerate() {
+                *c = <$field>::rand(rng);
+                *p = x.pow([i as u64]);
+            }
+            assert_eq!(
+                <$field>::sum_of_products_iter(coeffs, powers),
+                <$field>::sum_of_products(&coeffs, &powers_array)
+            );
+
+            // Large operands.
+            let two_inv = <$field>::from(2u64).inverse().unwrap();
+            let max = -two_inv - <$field>::one();
+            for len in [1, 10, 16, 17, 40] {
+                let a = vec![max; len];
+                let expected = a.iter().map(|a| *a * a).sum::<$field>();
+                assert_eq!(<$field>::sum_of_products_iter(a.clone(), a), expected, "length: {len}");
+            }
+        }
+
+        #[test]
+        fn test_is_square() {
+            use ark_std::UniformRand;
+            let rng = &mut test_rng();
+
+            assert!(<$field>::zero().is_square());
+            assert!(<$field>::one().is_square());
+
+            let mut num_squares = 0;
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(rng);
+                assert!(a.square().is_square());
+                let is_square = a.is_square();
+                assert_eq!(is_square, !a.legendre().is_qnr());
+                num_squares += usize::from(is_square);
+            }
+            // Roughly half of all elements are squares.
+            assert!(num_squares > 0 && num_squares < ITERATIONS);
+        }
+
+        #[test]
+        fn test_cube_root() {
+            let rng = &mut test_rng();
+
+            assert_eq!(<$field>::zero().cube_root(), Some(<$field>::zero()));
+            let one = <$field>::one();
+            let root = one.cube_root().unwrap();
+            assert_eq!(root.square() * root, one);
+
+            for _ in 0..10 {
+                let a = <$field>::rand(rng);
+                let b = a.square() * a;
+                let root = b.cube_root().unwrap();
+                assert_eq!(root.square() * root, b);
+
+                match a.cube_root() {
+                    Some(root) => assert_eq!(root.square() * root, a),
+                    // Multiplying by a nonzero cube preserves cubic non-residuosity.
+                    None => assert!((a * b).cube_root().is_none() || b.is_zero()),
+                }
+            }
+        }
+
+        #[test]
+        fn test_pow_rational() {
+            let rng = &mut test_rng();
+            for _ in 0..10 {
+                let a = <$field>::rand(rng);
+                assert_eq!(a.pow_rational(2, 1), Some(a.square()));
+
+                if <$field>::SQRT_PRECOMP.is_some() {
+                    let r = a.pow_rational(2, 2).unwrap();
+                    assert!(r == a || r == -a);
+                    if a.legendre().is_qnr() {
+                        assert!(a.pow_rational(1, 2).is_none());
+                        assert!(a.pow_rational(3, 2).is_none());
+                    } else {
+                        let r = a.pow_rational(3, 2).unwrap();
+                        assert_eq!(r.square(), a.square() * a);
+                    }
+                }
+
+                let r = a.pow_rational(3, 3).unwrap();
+                assert_eq!(r.square() * r, a.square() * a);
+                assert_eq!(a.pow_rational(1, 3), a.cube_root());
+            }
+        }
+
         #[test]
         fn test_sqrt() {
             if <$field>::SQRT_PRECOMP.is_some() {
@@ -343,7 +809,17 @@ macro_rules! __test_field {
                     let a = <$field>::rand(rng);
                     let b = a.square();
                     assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+
+                    // Both roots are returned, smaller one first.
+                    let (r0, r1) = b.sqrt_both().unwrap();
+                    assert!(r0 < r1);
+                    assert_eq!(r0, -r1);
+                    assert!(a == r0 || a == r1);
+                    assert_eq!((-a).square().sqrt_both(), Some((r0, r1)));
                 }
+
+                let zero = <$field>::zero();
+                assert_eq!(zero.sqrt_both(), Some((zero, zero)));
             }
         }
     };
@@ -382,10 +858,191 @@ macro_rules! __test_field {
                 }
             }
         }
+
+        #[test]
+        fn test_try_get_root_of_unity() {
+            use ark_ff::{FftField, RootOfUnityError};
+            for log_n in 0..=<$field>::TWO_ADICITY {
+                let n = 1 << log_n;
+                assert_eq!(
+                    <$field>::try_get_root_of_unity(n),
+                    Ok(<$field>::get_root_of_unity(n).unwrap())
+                );
+            }
+            assert_eq!(<$field>::try_get_root_of_unity(0), Err(RootOfUnityError::NotSmooth));
+            assert_eq!(
+                <$field>::try_get_root_of_unity(1 << (<$field>::TWO_ADICITY + 1)),
+                Err(RootOfUnityError::OrderTooLarge)
+            );
+            match <$field>::SMALL_SUBGROUP_BASE {
+                None => {
+                    assert_eq!(
+                        <$field>::try_get_root_of_unity(3),
+                        Err(RootOfUnityError::MissingSubgroupParams)
+                    );
+                    assert_eq!(<$field>::get_root_of_unity(3), None);
+                },
+                Some(q) => {
+                    let q = q as u64;
+                    let adicity = <$field>::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+                    let other_prime = if q == 7 { 11 } else { 7 };
+                    assert_eq!(
+                        <$field>::try_get_root_of_unity(2 * other_prime),
+                        Err(RootOfUnityError::NotSmooth)
+                    );
+                    assert_eq!(
+                        <$field>::try_get_root_of_unity(q.pow(adicity + 1)),
+                        Err(RootOfUnityError::OrderTooLarge)
+                    );
+                    let n = 2 * q.pow(adicity);
+                    let omega = <$field>::try_get_root_of_unity(n).unwrap();
+                    assert!(omega.pow([n]).is_one());
+                },
+            }
+        }
+
+        #[test]
+        fn test_get_small_subgroup_root_of_unity() {
+            use ark_ff::FftField;
+            match <$field>::SMALL_SUBGROUP_BASE {
+                None => assert_eq!(<$field>::get_small_subgroup_root_of_unity(0), None),
+                Some(q) => {
+                    let q = q as u64;
+                    let adicity = <$field>::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+                    assert!(<$field>::get_small_subgroup_root_of_unity(0).unwrap().is_one());
+                    for k in 1..=adicity {
+                        let omega = <$field>::get_small_subgroup_root_of_unity(k).unwrap();
+                        // The order of `omega` is exactly `q^k`.
+                        assert!(omega.pow([q.pow(k)]).is_one());
+                        assert!(!omega.pow([q.pow(k - 1)]).is_one());
+                        assert_eq!(Some(omega), <$field>::get_root_of_unity(q.pow(k)));
+                    }
+                    assert_eq!(<$field>::get_small_subgroup_root_of_unity(adicity + 1), None);
+                },
+            }
+        }
+
+        #[test]
+        fn test_get_inverse_root_of_unity() {
+            use ark_ff::FftField;
+            for log_n in 0..=<$field>::TWO_ADICITY {
+                let n = 1 << log_n;
+                let omega = <$field>::get_root_of_unity(n).unwrap();
+                let omega_inv = <$field>::get_inverse_root_of_unity(n).unwrap();
+                assert!((omega * omega_inv).is_one());
+                assert_eq!(Some(omega_inv), omega.inverse());
+            }
+            assert_eq!(<$field>::get_inverse_root_of_unity(0), None);
+            assert_eq!(
+                <$field>::get_inverse_root_of_unity(1 << (<$field>::TWO_ADICITY + 1)),
+                None
+            );
+            if let Some(q) = <$field>::SMALL_SUBGROUP_BASE {
+                let n = 2 * q as u64;
+                let omega = <$field>::get_root_of_unity(n).unwrap();
+                let omega_inv = <$field>::get_inverse_root_of_unity(n).unwrap();
+                assert!((omega * omega_inv).is_one());
+            } else {
+                assert_eq!(<$field>::get_inverse_root_of_unity(3), None);
+            }
+        }
+
+        #[test]
+        fn test_get_coset() {
+            use ark_ff::FftField;
+            let g = <$field>::coset_generator();
+            for log_n in 0..=<$field>::TWO_ADICITY {
+                let n = 1 << log_n;
+                let (omega, coset_g) = <$field>::get_coset(n).unwrap();
+                assert_eq!(coset_g, g);
+                assert_eq!(omega, <$field>::get_root_of_unity(n).unwrap());
+                assert!(omega.pow([n]).is_one());
+                assert!(!g.pow([n]).is_one());
+            }
+            assert!(<$field>::get_coset(1 << (<$field>::TWO_ADICITY + 1)).is_none());
+        }
+
+        #[test]
+        fn test_mul_by_generator_inv_pow() {
+            use ark_ff::FftField;
+            let g_inv = <$field>::GENERATOR.inverse().unwrap();
+            assert_eq!(<$field>::generator_inv(), g_inv);
+
+            let mut rng = test_rng();
+            for i in [0u64, 1, 2, 63, 1 << 40] {
+                let a = <$field>::rand(&mut rng);
+                assert_eq!(a.mul_by_generator_inv_pow(i), a * g_inv.pow([i]));
+            }
+            let a = <$field>::rand(&mut rng);
+            assert_eq!(a.mul_by_generator_inv_pow(5) * <$field>::GENERATOR.pow([5]), a);
+        }
+
+        #[test]
+        fn test_inverse_root_of_unity() {
+            use ark_ff::FftField;
+            for log_n in 0..=<$field>::TWO_ADICITY {
+                let omega = <$field>::get_root_of_unity(1 << log_n).unwrap();
+                assert_eq!(omega.inverse_root_of_unity(log_n), omega.inverse().unwrap());
+            }
+        }
+
+        #[test]
+        fn test_primitive_nth_root_powers_bit_reversed() {
+            use ark_ff::FftField;
+            for log_n in 0..=<$field>::TWO_ADICITY.min(10) {
+                let omega = <$field>::get_root_of_unity(1 << log_n).unwrap();
+                let powers = <$field>::primitive_nth_root_powers_bit_reversed(log_n).unwrap();
+                assert_eq!(powers.len(), 1 << log_n);
+                // Bit-reversing the output yields the powers in natural order.
+                let mut expected = <$field>::one();
+                for i in 0..(1usize << log_n) {
+                    let rev_i = i.reverse_bits().checked_shr(usize::BITS - log_n).unwrap_or(0);
+                    assert_eq!(powers[rev_i], expected);
+                    expected *= omega;
+                }
+            }
+            assert!(<$field>::primitive_nth_root_powers_bit_reversed(<$field>::TWO_ADICITY + 1).is_none());
+        }
     };
     ($field: ty; prime) => {
         $crate::__test_field!($field; fft);
 
+        #[test]
+        fn test_chi_squared_uniformity() {
+            // The 99.9% quantile of the chi-squared distribution with 15 degrees of freedom.
+            const BUCKETS: usize = 16;
+            const CRITICAL_VALUE: f64 = 37.697;
+            const SAMPLES: usize = 4096;
+            let mut rng = test_rng();
+
+            let samples = (0..SAMPLES)
+                .map(|_| <$field>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let statistic = $crate::fields::chi_squared_uniformity(&samples, BUCKETS);
+            assert!(statistic < CRITICAL_VALUE, "rand: {}", statistic);
+
+            let num_bytes = ((<$field>::MODULUS_BIT_SIZE + 7) / 8) as usize;
+            let samples = (0..SAMPLES)
+                .map(|_| loop {
+                    let bytes = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+                    if let Some(f) = <$field>::from_random_bytes(&bytes) {
+                        break f;
+                    }
+                })
+                .collect::<Vec<_>>();
+            let statistic = $crate::fields::chi_squared_uniformity(&samples, BUCKETS);
+            assert!(statistic < CRITICAL_VALUE, "from_random_bytes: {}", statistic);
+
+            let samples = (0..SAMPLES)
+                .map(|_| {
+                    let bytes = (0..num_bytes + 16).map(|_| rng.gen()).collect::<Vec<u8>>();
+                    <$field>::from_le_bytes_mod_order(&bytes)
+                })
+                .collect::<Vec<_>>();
+            let statistic = $crate::fields::chi_squared_uniformity(&samples, BUCKETS);
+            assert!(statistic < CRITICAL_VALUE, "from_le_bytes_mod_order: {}", statistic);
+        }
+
         #[test]
         fn test_sum_of_products_edge_case() {
             use ark_ff::BigInteger;
@@ -412,6 +1069,551 @@ macro_rules! __test_field {
             $crate::fields::prime_field_sum_of_products_test_helper::<_, 10>(a_max, b_max);
         }
 
+        #[test]
+        fn test_sum_of_products_iter_edge_case() {
+            let max = -<$field>::one(); // p - 1.
+            for len in 1..=40 {
+                let a = vec![max; len];
+                let expected = <$field>::from(len as u64);
+                assert_eq!(<$field>::sum_of_products_iter(a.clone(), a), expected, "length: {len}");
+            }
+        }
+
+        #[test]
+        fn test_to_from_bits() {
+            use ark_ff::BigInteger;
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            let num_bits = <$field>::MODULUS_BIT_SIZE as usize;
+
+            let minus_one = -<$field>::one();
+            let bits = minus_one.to_bits_be();
+            assert_eq!(bits.len(), num_bits);
+            assert!(bits[0]);
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let bits_le = a.to_bits_le();
+                let bits_be = a.to_bits_be();
+                assert_eq!(bits_le.len(), num_bits);
+                assert_eq!(bits_be.len(), num_bits);
+                assert_eq!(&a.into_bigint().to_bits_le()[..num_bits], &bits_le[..]);
+                assert_eq!(<$field>::from_bits_le(&bits_le), a);
+                assert_eq!(<$field>::from_bits_be(&bits_be), a);
+
+                // Inputs larger than the modulus are reduced.
+                let mut modulus_plus_a = <$field>::MODULUS;
+                let carry = modulus_plus_a.add_with_carry(&a.into_bigint());
+                let mut bits = modulus_plus_a.to_bits_le();
+                bits.push(carry);
+                assert_eq!(<$field>::from_bits_le(&bits), a);
+                bits.reverse();
+                assert_eq!(<$field>::from_bits_be(&bits), a);
+            }
+        }
+
+        #[test]
+        fn test_from_random_bytes_consumed_prime() {
+            let num_bytes = ((<$field>::MODULUS_BIT_SIZE + 7) / 8) as usize;
+            let mut rng = test_rng();
+
+            // Drain a stream of random bytes into field elements by rejection
+            // sampling, advancing by the number of bytes consumed each time.
+            let stream = (0..100 * num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let mut cursor = 0;
+            while stream.len() - cursor >= num_bytes {
+                let bytes = &stream[cursor..];
+                match <$field>::from_random_bytes_consumed(bytes) {
+                    Some((f, consumed)) => {
+                        assert_eq!(consumed, num_bytes);
+                        assert_eq!(Some(f), <$field>::from_random_bytes(&bytes[..consumed]));
+                        cursor += consumed;
+                    },
+                    None => cursor += num_bytes,
+                }
+            }
+
+            let (_, consumed) = <$field>::from_random_bytes_consumed(&stream[..num_bytes / 2]).unwrap();
+            assert_eq!(consumed, num_bytes / 2);
+        }
+
+        #[test]
+        fn test_modulus_minus_one() {
+            use ark_ff::BigInteger;
+            let mut expected = <$field>::MODULUS;
+            expected.sub_with_borrow(&1u64.into());
+            assert_eq!(<$field>::MODULUS_MINUS_ONE, expected);
+            assert_eq!(<$field>::NEG_ONE.into_bigint(), expected);
+        }
+
+        #[test]
+        fn test_classify_01_all_limbs() {
+            use ark_ff::BigInteger;
+            // These values differ from zero and one only in the most
+            // significant limb.
+            let mut x = <$field as PrimeField>::BigInt::from(0u64);
+            let top = x.as_ref().len() - 1;
+            let top_bit = (<$field>::MODULUS_BIT_SIZE - 2) % 64;
+            x.as_mut()[top] = 1 << top_bit;
+            let high = <$field>::from_bigint(x).unwrap();
+            if top > 0 {
+                assert_eq!(high.classify_01(), 2);
+                assert_eq!((high + <$field>::one()).classify_01(), 2);
+            }
+        }
+
+        #[test]
+        fn test_from_fixed_point() {
+            let two = <$field>::from(2u8);
+            assert_eq!(<$field>::from_fixed_point(3, 1), <$field>::from(3u8) / two);
+            assert_eq!(<$field>::from_fixed_point(-3, 1), -<$field>::from(3u8) / two);
+            assert_eq!(<$field>::from_fixed_point(0, 7), <$field>::zero());
+            assert_eq!(<$field>::from_fixed_point(5, 0), <$field>::from(5u8));
+            assert_eq!(<$field>::from_fixed_point(-5, 0), -<$field>::from(5u8));
+            assert_eq!(
+                <$field>::from_fixed_point(i64::MIN, 63),
+                -<$field>::one()
+            );
+
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let value = rng.gen::<i64>().max(-i64::MAX);
+                let frac_bits = rng.gen_range(0..128u32);
+                let encoded = <$field>::from_fixed_point(value, frac_bits);
+                // Scaling back by `2^frac_bits` recovers the integer `value`.
+                let scaled = encoded * two.pow([u64::from(frac_bits)]);
+                assert_eq!(scaled, <$field>::from_fixed_point(value, 0));
+                assert_eq!(<$field>::from_fixed_point(-value, frac_bits), -encoded);
+            }
+        }
+
+        #[test]
+        fn test_prime_field_characteristic() {
+            use $crate::num_bigint::BigUint;
+            assert_eq!(<$field>::characteristic_bigint(), <$field>::MODULUS);
+            assert_eq!(<$field>::characteristic_biguint(), BigUint::from(<$field>::MODULUS));
+        }
+
+        #[test]
+        fn test_sum_matches_fold() {
+            use ark_std::vec::Vec;
+            let mut rng = test_rng();
+            let v: Vec<$field> = (0..10_000).map(|_| <$field>::rand(&mut rng)).collect();
+            let expected = v.iter().fold(<$field>::zero(), |sum, x| sum + x);
+            assert_eq!(v.iter().sum::<$field>(), expected);
+            assert_eq!(v.into_iter().sum::<$field>(), expected);
+
+            let minus_one = -<$field>::one();
+            let n = 1000u64;
+            assert_eq!(ark_std::iter::repeat(minus_one).take(n as usize).sum::<$field>(), -<$field>::from(n));
+            assert_eq!(ark_std::iter::empty::<$field>().sum::<$field>(), <$field>::zero());
+        }
+
+        #[test]
+        fn test_hex_string() {
+            use ark_ff::{BigInteger, HexParseError};
+            use ark_std::format;
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let hex = a.to_hex_string();
+                assert_eq!(<$field>::from_hex_string(&hex), Ok(a));
+                assert_eq!(<$field>::from_hex_string(&hex[2..]), Ok(a));
+                assert_eq!(<$field>::from_hex_string(&hex.to_uppercase()[2..]), Ok(a));
+            }
+
+            assert_eq!(<$field>::from(31u8).to_hex_string(), "0x1f");
+            assert_eq!(<$field>::zero().to_hex_string(), "0x0");
+            assert_eq!(<$field>::from_hex_string("0X001F"), Ok(<$field>::from(31u8)));
+            assert_eq!(<$field>::from_hex_string("0"), Ok(<$field>::zero()));
+
+            // `p - 1` round-trips, while `p` and larger values are rejected.
+            let max = <$field>::NEG_ONE.to_hex_string();
+            assert_eq!(<$field>::from_hex_string(&max), Ok(<$field>::NEG_ONE));
+            let modulus = format!("{:X}", <$field>::MODULUS);
+            assert_eq!(<$field>::from_hex_string(&modulus), Err(HexParseError::OutOfRange));
+            let num_digits = <$field>::MODULUS.as_ref().len() * 16;
+            let all_ones = "f".repeat(num_digits);
+            assert_eq!(<$field>::from_hex_string(&all_ones), Err(HexParseError::OutOfRange));
+            let too_long = "1".repeat(num_digits + 1);
+            assert_eq!(<$field>::from_hex_string(&too_long), Err(HexParseError::OutOfRange));
+            let padded = format!("0x{}{}", "0".repeat(100), &max[2..]);
+            assert_eq!(<$field>::from_hex_string(&padded), Ok(<$field>::NEG_ONE));
+
+            assert_eq!(<$field>::from_hex_string(""), Err(HexParseError::Empty));
+            assert_eq!(<$field>::from_hex_string("0x"), Err(HexParseError::Empty));
+            assert_eq!(<$field>::from_hex_string("0xg"), Err(HexParseError::InvalidDigit));
+            assert_eq!(<$field>::from_hex_string("-1"), Err(HexParseError::InvalidDigit));
+            assert_eq!(<$field>::from_hex_string("0x 1"), Err(HexParseError::InvalidDigit));
+        }
+
+        #[test]
+        fn test_sort_key_bytes() {
+            let num_bytes = ((<$field>::MODULUS_BIT_SIZE + 7) / 8) as usize;
+            let mut rng = test_rng();
+            let mut elements: Vec<$field> = (0..ITERATIONS).map(|_| <$field>::rand(&mut rng)).collect();
+            elements.extend([<$field>::zero(), <$field>::one(), <$field>::NEG_ONE]);
+            for a in &elements {
+                let key = a.sort_key_bytes();
+                assert_eq!(key.len(), num_bytes);
+                for b in &elements {
+                    let a_int = a.into_bigint();
+                    let b_int = b.into_bigint();
+                    assert_eq!(key.cmp(&b.sort_key_bytes()), a_int.cmp(&b_int));
+                }
+            }
+            assert!(<$field>::zero().sort_key_bytes().iter().all(|b| *b == 0));
+            assert_eq!(<$field>::one().sort_key_bytes().last(), Some(&1));
+        }
+
+        #[test]
+        fn test_short_fingerprint() {
+            use ark_std::{format, string::String};
+            let mut rng = test_rng();
+            let mut fingerprints = Vec::new();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let fingerprint = a.short_fingerprint();
+                let key = a.sort_key_bytes();
+                let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+                if key.len() <= 4 {
+                    // Short encodings are returned in full, without an ellipsis.
+                    assert_eq!(fingerprint, hex(&key));
+                } else {
+                    let (head, tail) = fingerprint.split_once('…').unwrap();
+                    assert_eq!(head.len(), 4);
+                    assert_eq!(tail.len(), 4);
+                    assert!(head.chars().chain(tail.chars()).all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+                    assert_eq!(head, hex(&key[..2]));
+                    assert_eq!(tail, hex(&key[key.len() - 2..]));
+                }
+                fingerprints.push(fingerprint);
+            }
+            // Distinct random elements almost always have distinct fingerprints.
+            fingerprints.sort();
+            fingerprints.dedup();
+            assert!(fingerprints.len() >= ITERATIONS * 99 / 100);
+
+            assert!(<$field>::one().short_fingerprint().ends_with("0001"));
+            assert!(<$field>::zero().short_fingerprint().starts_with("0000"));
+        }
+
+        #[test]
+        fn test_flag_bit_capacity() {
+            use ark_serialize::{
+                CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags,
+            };
+            use $crate::fields::TopBitsFlags;
+
+            let capacity = <$field>::flag_bit_capacity();
+            assert!(capacity < 8);
+            assert_eq!((<$field>::MODULUS_BIT_SIZE as usize + capacity) % 8, 0);
+
+            fn check<Fl: Flags + PartialEq + core::fmt::Debug>(a: $field, flags: Fl) {
+                let size = a.compressed_size();
+                let fits = Fl::BIT_SIZE <= <$field>::flag_bit_capacity();
+                assert_eq!(a.serialized_size_with_flags::<Fl>(), size + usize::from(!fits));
+
+                let mut bytes = vec![0u8; size];
+                let result = a.serialize_with_flags(&mut bytes[..], flags);
+                if fits {
+                    result.unwrap();
+                    let (b, b_flags) = <$field>::deserialize_with_flags::<_, Fl>(&bytes[..]).unwrap();
+                    assert_eq!((b, b_flags), (a, flags));
+                } else {
+                    // A flag that does not fit must not be written in place of
+                    // value bits.
+                    assert!(result.is_err());
+                }
+            }
+
+            let mut rng = test_rng();
+            for _ in 0..10 {
+                let a = <$field>::rand(&mut rng);
+                check(a, TopBitsFlags::<1>);
+                check(a, TopBitsFlags::<2>);
+                check(a, TopBitsFlags::<3>);
+                check(a, TopBitsFlags::<4>);
+                check(a, TopBitsFlags::<5>);
+                check(a, TopBitsFlags::<6>);
+                check(a, TopBitsFlags::<7>);
+                check(a, TopBitsFlags::<8>);
+            }
+        }
+
+        #[test]
+        fn test_reduce_with_quotient() {
+            use $crate::num_bigint::BigUint;
+            let mut rng = test_rng();
+            let modulus = BigUint::from(<$field>::MODULUS);
+            for bits in [0, 1, 64, <$field>::MODULUS_BIT_SIZE as usize, 1000] {
+                for _ in 0..ITERATIONS {
+                    let bytes: ark_std::vec::Vec<u8> = (0..(bits + 7) / 8).map(|_| rng.gen()).collect();
+                    let value = BigUint::from_bytes_le(&bytes);
+                    let (r, q) = <$field>::reduce_with_quotient(&value);
+                    assert_eq!(&q * &modulus + BigUint::from(r.into_bigint()), value);
+                    assert_eq!(r, <$field>::from(value));
+                }
+            }
+            let (r, q) = <$field>::reduce_with_quotient(&(&modulus * 3u8 + 1u8));
+            assert_eq!((r, q), (<$field>::one(), BigUint::from(3u8)));
+        }
+
+        #[test]
+        fn test_balanced_bigint() {
+            use $crate::num_bigint::{BigInt, BigUint};
+            let mut rng = test_rng();
+            let modulus = BigInt::from(BigUint::from(<$field>::MODULUS));
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let balanced = a.to_balanced_bigint();
+                assert!(-&modulus < &balanced * 2u8 && &balanced * 2u8 <= modulus);
+                assert_eq!(<$field>::from_bigint_signed(&balanced), a);
+                assert_eq!(<$field>::from_bigint_signed(&-&balanced), -a);
+                assert_eq!(<$field>::from_bigint_signed(&(&balanced + &modulus * 3u8)), a);
+                assert_eq!(<$field>::from_bigint_signed(&(&balanced - &modulus * 3u8)), a);
+            }
+            let minus_one = BigInt::from(-1);
+            assert_eq!(<$field>::from_bigint_signed(&minus_one), -<$field>::one());
+            assert_eq!((-<$field>::one()).to_balanced_bigint(), minus_one);
+            assert_eq!(<$field>::zero().to_balanced_bigint(), BigInt::from(0));
+            let half = BigInt::from(BigUint::from(<$field>::MODULUS_MINUS_ONE_DIV_TWO));
+            let a = <$field>::from_bigint_signed(&half);
+            assert_eq!(a.to_balanced_bigint(), half);
+            assert_eq!((a + <$field>::one()).to_balanced_bigint(), -half);
+        }
+
+        #[test]
+        fn test_from_base_field_digits() {
+            use ark_ff::{BigInteger, BitIteratorLE};
+            use ark_std::vec::Vec;
+            let mut rng = test_rng();
+            for radix_bits in [1u32, 7, 16, 64] {
+                for _ in 0..ITERATIONS {
+                    let a = <$field>::rand(&mut rng);
+                    let bits: Vec<bool> = BitIteratorLE::new(a.into_bigint()).collect();
+                    let digits: Vec<$field> = bits
+                        .chunks(radix_bits as usize)
+                        .map(|chunk| {
+                            <$field>::from_bigint(BigInteger::from_bits_le(chunk)).unwrap()
+                        })
+                        .collect();
+                    assert_eq!(<$field>::from_base_field_digits(&digits, radix_bits), a);
+                }
+            }
+            assert_eq!(<$field>::from_base_field_digits(&[], 8), <$field>::zero());
+
+            // The value is reduced if it is not smaller than the modulus.
+            let digits = [<$field>::from(1u8); 300];
+            let expected = (0..300).fold(<$field>::zero(), |acc, _| acc.double() + <$field>::one());
+            assert_eq!(<$field>::from_base_field_digits(&digits, 1), expected);
+        }
+
+        #[test]
+        fn test_str_bytes() {
+            use ark_std::string::String;
+            for s in ["", "a", "tag", "domain-separator", "Hello, world!", "ünïcödé"] {
+                let a = <$field>::from_str_bytes(s).unwrap();
+                assert_eq!(a.try_into_str().as_deref(), Some(s));
+            }
+            assert_eq!(<$field>::from_str_bytes("").unwrap(), <$field>::zero());
+            assert_eq!(<$field>::from_str_bytes("a").unwrap(), <$field>::from(b'a'));
+            assert_eq!(<$field>::from_str_bytes("ab").unwrap(), <$field>::from(0x6261u64));
+            assert_eq!(<$field>::from_str_bytes("a\0"), None);
+
+            // Strings longer than the modulus do not fit.
+            let num_bytes = ((<$field>::MODULUS_BIT_SIZE + 7) / 8) as usize;
+            let long: String = core::iter::repeat('x').take(num_bytes + 1).collect();
+            assert_eq!(<$field>::from_str_bytes(&long), None);
+            let full: String = core::iter::repeat('~').take(num_bytes).collect();
+            assert_eq!(<$field>::from_str_bytes(&full), None);
+
+            // Not every element encodes a valid string.
+            assert_eq!(<$field>::from(0xffu64).try_into_str(), None);
+        }
+
+        #[test]
+        fn test_hensel_lift_sqrt() {
+            use ark_ff::BigInteger;
+            use $crate::num_bigint::BigUint;
+            let p: BigUint = <$field>::MODULUS.into();
+            let precision = 4;
+            let p_pow = p.pow(precision);
+
+            let mut rng = test_rng();
+            for _ in 0..10 {
+                let a = <$field>::rand(&mut rng);
+                let digits = match a.hensel_lift_sqrt(precision) {
+                    Some(digits) => digits,
+                    None => {
+                        assert!(a.sqrt().is_none());
+                        continue;
+                    },
+                };
+                assert_eq!(digits.len(), precision as usize);
+                // The first digit is the root modulo `p`.
+                assert_eq!(digits[0], a.sqrt().unwrap().into_bigint());
+
+                // `root^2 = a mod p^precision`.
+                let root = digits
+                    .iter()
+                    .rev()
+                    .fold(BigUint::from(0u8), |acc, d| acc * &p + BigUint::from(*d));
+                let a_int: BigUint = a.into_bigint().into();
+                assert_eq!(root.modpow(&BigUint::from(2u8), &p_pow), a_int);
+            }
+
+            let zero = <$field>::zero().hensel_lift_sqrt(3).unwrap();
+            assert!(zero.iter().all(|d| d.is_zero()));
+            assert!(<$field>::one().hensel_lift_sqrt(0).unwrap().is_empty());
+            let non_residue = <$field>::GENERATOR;
+            assert_eq!(non_residue.hensel_lift_sqrt(2), None);
+        }
+
+        #[test]
+        fn test_from_f64() {
+            let two = <$field>::from(2u8);
+            let half = <$field>::one() / two;
+            assert_eq!(<$field>::from_f64(0.5, 1), Some(half));
+            assert_eq!(<$field>::from_f64(-0.5, 1), Some(-half));
+            assert_eq!(<$field>::from_f64(0.0, 10), Some(<$field>::zero()));
+            assert_eq!(<$field>::from_f64(-0.0, 0), Some(<$field>::zero()));
+            assert_eq!(<$field>::from_f64(3.0, 0), Some(<$field>::from(3u8)));
+
+            // `1.3 * 4 = 5.2` rounds to `5`, and `0.375 * 4 = 1.5` to `2`.
+            let four = <$field>::from(4u8);
+            assert_eq!(<$field>::from_f64(1.3, 2), Some(<$field>::from(5u8) / four));
+            assert_eq!(<$field>::from_f64(0.375, 2), Some(two / four));
+            assert_eq!(<$field>::from_f64(-0.375, 2), Some(-two / four));
+            assert_eq!(<$field>::from_f64(0.1, 0), Some(<$field>::zero()));
+
+            assert_eq!(<$field>::from_f64(f64::NAN, 4), None);
+            assert_eq!(<$field>::from_f64(f64::INFINITY, 4), None);
+            assert_eq!(<$field>::from_f64(f64::NEG_INFINITY, 0), None);
+            // `2^63` does not fit in an `i64`, but `-2^63` does.
+            assert_eq!(<$field>::from_f64(1.0, 63), None);
+            assert_eq!(<$field>::from_f64(-1.0, 63), Some(-<$field>::one()));
+            assert_eq!(<$field>::from_f64(1e300, 0), None);
+            assert_eq!(<$field>::from_f64(f64::MIN_POSITIVE, u32::MAX), None);
+            assert_eq!(<$field>::from_f64(f64::MIN_POSITIVE, 0), Some(<$field>::zero()));
+        }
+
+        #[test]
+        fn test_digit_sum() {
+            let x = <$field>::from(12345u64);
+            assert_eq!(x.digit_sum(10), 15);
+            // 12345 = 0o30071 = 0b11000000111001
+            assert_eq!(x.digit_sum(8), 11);
+            assert_eq!(x.digit_sum(2), 6);
+            assert_eq!(x.digit_sum(100_000), 12345);
+            assert_eq!(<$field>::zero().digit_sum(10), 0);
+
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let x = <$field>::rand(&mut rng);
+                let ones: u32 = x.into_bigint().as_ref().iter().map(|l| l.count_ones()).sum();
+                assert_eq!(x.digit_sum(2), u64::from(ones));
+            }
+        }
+
+        #[test]
+        fn test_continued_fraction() {
+            use $crate::num_bigint::BigUint;
+            type BigInt = <$field as PrimeField>::BigInt;
+            let to_biguint = |x: BigInt| -> BigUint { x.into() };
+
+            let one = BigInt::from(1u64);
+            let zero = BigInt::from(0u64);
+            assert_eq!(<$field>::one().continued_fraction(10), vec![zero, <$field>::MODULUS]);
+            assert_eq!(<$field>::zero().continued_fraction(10), vec![zero]);
+            assert!(<$field>::one().continued_fraction(0).is_empty());
+            // `(p - 1) / p = 0 + 1 / (1 + 1 / (p - 1))`
+            assert_eq!(
+                (-<$field>::one()).continued_fraction(10),
+                vec![zero, one, <$field>::MODULUS_MINUS_ONE]
+            );
+
+            // The expansion evaluates back to `x / p`.
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let x = <$field>::rand(&mut rng);
+                let quotients = x.continued_fraction(usize::MAX);
+                let (mut h, mut h_prev) = (BigUint::from(1u8), BigUint::from(0u8));
+                let (mut k, mut k_prev) = (BigUint::from(0u8), BigUint::from(1u8));
+                for q in quotients {
+                    let q = to_biguint(q);
+                    let h_next = &q * &h + &h_prev;
+                    let k_next = &q * &k + &k_prev;
+                    h_prev = core::mem::replace(&mut h, h_next);
+                    k_prev = core::mem::replace(&mut k, k_next);
+                }
+                let p = to_biguint(<$field>::MODULUS);
+                assert_eq!(h * &p, to_biguint(x.into_bigint()) * k);
+            }
+        }
+
+        #[test]
+        fn test_tower_pow() {
+            use ark_ff::BigInteger;
+            type BigInt = <$field as PrimeField>::BigInt;
+            let big = |x: u64| BigInt::from(x);
+            let mut rng = test_rng();
+            for _ in 0..10 {
+                let x = <$field>::rand(&mut rng);
+                assert_eq!(x.tower_pow(&big(3), &big(4)), x.pow([81]));
+                assert_eq!(x.tower_pow(&big(7), &big(0)), x);
+                // 2^70 = 2^6 * 2^64
+                assert_eq!(x.tower_pow(&big(2), &big(70)), x.pow([0, 1 << 6]));
+
+                // The exponent `(p - 1)^k` is reduced to zero for `k > 0`.
+                let mut order = <$field>::MODULUS;
+                order.sub_with_borrow(&big(1));
+                assert_eq!(x.tower_pow(&order, &big(5)), <$field>::one());
+                assert_eq!(x.tower_pow(&order, &big(0)), x);
+                // The exponent `(p - 1)^(p - 1) + 1` would not fit into memory.
+                let mut modulus = order;
+                modulus.add_with_carry(&big(1));
+                assert_eq!(x.tower_pow(&modulus, &order), x);
+            }
+
+            let zero = <$field>::zero();
+            assert_eq!(zero.tower_pow(&big(0), &big(3)), <$field>::one());
+            assert_eq!(zero.tower_pow(&big(0), &big(0)), zero);
+            assert_eq!(zero.tower_pow(&big(5), &big(2)), zero);
+        }
+
+        #[test]
+        fn test_canonical_delta() {
+            use ark_ff::BigInteger;
+            let mut rng = test_rng();
+
+            let mut values = (0..ITERATIONS)
+                .map(|_| <$field>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            values.sort();
+            let mut prev = <$field>::zero();
+            for value in &values {
+                let delta = value.canonical_delta(&prev);
+                assert_eq!(<$field>::from_canonical_delta(&prev, &delta), *value);
+                prev = *value;
+            }
+
+            // Deltas between clustered values are much smaller than the values themselves.
+            let base = -<$field>::from(u64::MAX);
+            let mut values = (0..ITERATIONS)
+                .map(|_| base + <$field>::from(rng.gen::<u32>()))
+                .collect::<Vec<_>>();
+            values.sort();
+            let mut prev = values[0];
+            for value in &values[1..] {
+                let delta = value.canonical_delta(&prev);
+                assert!(delta.num_bits() <= 32);
+                assert!(delta < value.into_bigint());
+                assert_eq!(<$field>::from_canonical_delta(&prev, &delta), *value);
+                prev = *value;
+            }
+        }
+
         #[test]
         fn test_constants() {
             use ark_ff::{FpConfig, BigInteger, SqrtPrecomputation};
@@ -457,6 +1659,65 @@ macro_rules! __test_field {
     ($field: ty; mont_prime_field) => {
         $crate::__test_field!($field; prime);
 
+        #[test]
+        fn test_try_from_bytes() {
+            use ark_ff::BigInteger;
+            use ark_serialize::CanonicalSerialize;
+            let size = <$field>::zero().compressed_size();
+            let to_bytes = |x: <$field as PrimeField>::BigInt| {
+                let mut bytes = x.to_bytes_le();
+                bytes.resize(size, 0);
+                bytes
+            };
+
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let mut bytes = Vec::new();
+                a.serialize_compressed(&mut bytes).unwrap();
+                assert_eq!(<$field>::try_from(&bytes[..]).unwrap(), a);
+            }
+
+            // `p - 1` is the largest canonical encoding, and `p` is rejected.
+            let max = to_bytes(<$field>::MODULUS_MINUS_ONE);
+            assert_eq!(<$field>::try_from(&max[..]).unwrap(), <$field>::NEG_ONE);
+            let modulus = to_bytes(<$field>::MODULUS);
+            assert!(<$field>::try_from(&modulus[..]).is_err());
+            let all_ones = vec![0xff; size];
+            assert!(<$field>::try_from(&all_ones[..]).is_err());
+
+            // The length must match exactly.
+            let zero = vec![0u8; size + 1];
+            assert_eq!(<$field>::try_from(&zero[..size]).unwrap(), <$field>::zero());
+            assert!(<$field>::try_from(&zero[..]).is_err());
+            assert!(<$field>::try_from(&zero[..size - 1]).is_err());
+        }
+
+        #[test]
+        fn test_debug_repr() {
+            use ark_std::{string::ToString, UniformRand};
+            use $crate::num_bigint::BigUint;
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                let repr = a.debug_repr();
+                assert_eq!(repr.canonical_limbs, a.into_bigint().as_ref());
+                assert_eq!(repr.montgomery_limbs, a.as_montgomery_limbs());
+                // The Montgomery form is `a * R`.
+                let r = <$field>::from_bigint(<$field>::R).unwrap();
+                assert_eq!(repr.montgomery_limbs, (a * r).into_bigint().as_ref());
+
+                let canonical: BigUint = a.into();
+                assert_eq!(repr.decimal, canonical.to_string());
+                assert_eq!(BigUint::parse_bytes(repr.hex[2..].as_bytes(), 16), Some(canonical));
+                assert!(repr.hex.starts_with("0x"));
+            }
+            let repr = <$field>::from(255u64).debug_repr();
+            assert_eq!(repr.decimal, "255");
+            assert_eq!(repr.hex, "0xff");
+            assert_eq!(<$field>::zero().debug_repr().hex, "0x0");
+        }
+
         #[test]
         pub fn test_montgomery_config() {
             use ark_ff::{FpConfig, BigInteger};
@@ -503,9 +1764,80 @@ macro_rules! __test_field {
 
             assert_eq!(r, <$field>::R.into());
             assert_eq!(r2, <$field>::R2.into());
+            assert_eq!((&r * BigUint::from(<$field>::MONTGOMERY_R_INV)) % &modulus, BigUint::from(1u8));
             assert_eq!(inv, <$field>::INV.into());
             assert_eq!(inv2, <$field>::INV);
         }
+
+        #[test]
+        fn test_montgomery_r_inv() {
+            use $crate::num_bigint::BigUint;
+            let mut rng = test_rng();
+            let modulus: BigUint = <$field>::MODULUS.into();
+            let r_inv: BigUint = <$field>::MONTGOMERY_R_INV.into();
+            assert!(r_inv < modulus);
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                // Converting back from the Montgomery form `a * R`.
+                let montgomery: BigUint = a.0.into();
+                let canonical: BigUint = a.into_bigint().into();
+                assert_eq!((montgomery * &r_inv) % &modulus, canonical);
+            }
+        }
+
+        #[test]
+        fn test_reduce_wide() {
+            use ark_std::vec::Vec;
+            use $crate::num_bigint::BigUint;
+            let modulus: BigUint = <$field>::MODULUS.into();
+            let k = <$field>::MODULUS_BIT_SIZE;
+            let mu: BigUint = <$field>::BARRETT_MU.into();
+            assert_eq!(mu, (BigUint::from(1u8) << (2 * k)) / &modulus);
+
+            let check = |wide: &BigUint| {
+                let limbs = wide.to_u64_digits();
+                let expected = <$field>::from(wide % &modulus);
+                assert_eq!(<$field>::reduce_wide(&limbs), expected);
+            };
+            let mut rng = test_rng();
+            for _ in 0..ITERATIONS {
+                let a: BigUint = <$field>::rand(&mut rng).into();
+                let b: BigUint = <$field>::rand(&mut rng).into();
+                check(&(a * b));
+
+                let bytes: Vec<u8> = (0..(2 * k as usize + 7) / 8).map(|_| rng.gen()).collect();
+                check(&(BigUint::from_bytes_le(&bytes) % (BigUint::from(1u8) << (2 * k))));
+            }
+            let max = (BigUint::from(1u8) << (2 * k)) - 1u8;
+            check(&max);
+            check(&(&modulus * &modulus));
+            check(&(&modulus - 1u8));
+            check(&BigUint::from(0u8));
+            assert_eq!(<$field>::reduce_wide(&[]), <$field>::zero());
+        }
+
+        #[test]
+        fn test_from_foreign_montgomery() {
+            use ark_ff::BigInteger;
+            use $crate::num_bigint::BigUint;
+            let mut rng = test_rng();
+            let limbs = <$field as PrimeField>::BigInt::NUM_LIMBS;
+            let modulus: BigUint = <$field>::MODULUS.into();
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                // Our own Montgomery form uses `R = 2^(64 * N)`.
+                assert_eq!(<$field>::from_foreign_montgomery(a.0.as_ref(), (limbs * 64) as u32), a);
+                assert_eq!(<$field>::from_foreign_montgomery(a.into_bigint().as_ref(), 0), a);
+
+                let a_int: BigUint = a.into();
+                for foreign_r_bits in [64u32, 256, 384, 448] {
+                    let foreign = (&a_int << foreign_r_bits) % &modulus;
+                    let foreign_limbs = foreign.to_u64_digits();
+                    assert_eq!(<$field>::from_foreign_montgomery(&foreign_limbs, foreign_r_bits), a);
+                }
+            }
+        }
     }
 }
 
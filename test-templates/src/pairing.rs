@@ -49,6 +49,32 @@ macro_rules! test_pairing {
                     assert_eq!(ans1, ans2);
                 }
             }
+
+            #[test]
+            fn test_cyclotomic_square_batch() {
+                use ark_ff::CyclotomicMultSubgroup;
+                let rng = &mut test_rng();
+                for _ in 0..10 {
+                    let a = <$Pairing as Pairing>::G1::rand(rng);
+                    let b = <$Pairing as Pairing>::G2::rand(rng);
+                    // Pairing outputs lie in the cyclotomic subgroup.
+                    let f = <$Pairing>::pairing(a, b).0;
+                    for count in [0, 1, 2, 5, 63] {
+                        let mut expected = f;
+                        for _ in 0..count {
+                            expected.cyclotomic_square_in_place();
+                        }
+                        assert_eq!(
+                            f.cyclotomic_square_batch(count),
+                            expected,
+                            "count: {}",
+                            count
+                        );
+                    }
+                }
+                let one = <$Pairing as Pairing>::TargetField::one();
+                assert_eq!(one.cyclotomic_square_batch(63), one);
+            }
         }
     };
 }